@@ -0,0 +1,156 @@
+//! Line styles of the borders drawn around tables and panels.
+//!
+//! ```rust
+//! use terminal_font::border::Border;
+//!
+//! let widths = [3, 2];
+//! assert_eq!(Border::LIGHT.top(&widths), "┌───┬──┐");
+//! assert_eq!(Border::LIGHT.separator(&widths), "├───┼──┤");
+//! assert_eq!(Border::ROUNDED.bottom(&widths), "╰───┴──╯");
+//! assert_eq!(Border::ASCII.bottom(&widths), "+---+--+");
+//! ```
+
+/// Characters to draw a border, including the joints
+/// between the cells of a table.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Border {
+    pub horizontal: char,
+    pub vertical: char,
+    pub top_left: char,
+    pub top_right: char,
+    pub bottom_left: char,
+    pub bottom_right: char,
+
+    /// Joint between the top line and an inner vertical line, like `┬`.
+    pub top_joint: char,
+
+    /// Joint between the bottom line and an inner vertical line, like `┴`.
+    pub bottom_joint: char,
+
+    /// Joint between the left line and an inner horizontal line, like `├`.
+    pub left_joint: char,
+
+    /// Joint between the right line and an inner horizontal line, like `┤`.
+    pub right_joint: char,
+
+    /// Cross of the inner lines, like `┼`.
+    pub cross: char,
+}
+
+impl Border {
+    /// Only ASCII characters, works everywhere.
+    pub const ASCII: Border = Border::uniform('-', '|', '+');
+
+    /// Unicode light box drawing lines.
+    pub const LIGHT: Border = Border {
+        horizontal: '─',
+        vertical: '│',
+        top_left: '┌',
+        top_right: '┐',
+        bottom_left: '└',
+        bottom_right: '┘',
+        top_joint: '┬',
+        bottom_joint: '┴',
+        left_joint: '├',
+        right_joint: '┤',
+        cross: '┼',
+    };
+
+    /// Unicode heavy box drawing lines.
+    pub const HEAVY: Border = Border {
+        horizontal: '━',
+        vertical: '┃',
+        top_left: '┏',
+        top_right: '┓',
+        bottom_left: '┗',
+        bottom_right: '┛',
+        top_joint: '┳',
+        bottom_joint: '┻',
+        left_joint: '┣',
+        right_joint: '┫',
+        cross: '╋',
+    };
+
+    /// Unicode light box drawing lines with rounded corners.
+    pub const ROUNDED: Border = Border {
+        top_left: '╭',
+        top_right: '╮',
+        bottom_left: '╰',
+        bottom_right: '╯',
+        ..Border::LIGHT
+    };
+
+    /// Unicode double box drawing lines.
+    pub const DOUBLE: Border = Border {
+        horizontal: '═',
+        vertical: '║',
+        top_left: '╔',
+        top_right: '╗',
+        bottom_left: '╚',
+        bottom_right: '╝',
+        top_joint: '╦',
+        bottom_joint: '╩',
+        left_joint: '╠',
+        right_joint: '╣',
+        cross: '╬',
+    };
+
+    /// Border with the same character for all corners and joints.
+    pub const fn uniform(
+        horizontal: char,
+        vertical: char,
+        joint: char,
+    ) -> Self {
+        Border {
+            horizontal,
+            vertical,
+            top_left: joint,
+            top_right: joint,
+            bottom_left: joint,
+            bottom_right: joint,
+            top_joint: joint,
+            bottom_joint: joint,
+            left_joint: joint,
+            right_joint: joint,
+            cross: joint,
+        }
+    }
+
+    /// The top line above the cells of the given widths.
+    pub fn top(&self, widths: &[usize]) -> String {
+        self.line(widths, self.top_left, self.top_joint, self.top_right)
+    }
+
+    /// The horizontal line between two rows of cells of the given widths.
+    pub fn separator(&self, widths: &[usize]) -> String {
+        self.line(widths, self.left_joint, self.cross, self.right_joint)
+    }
+
+    /// The bottom line below the cells of the given widths.
+    pub fn bottom(&self, widths: &[usize]) -> String {
+        self.line(
+            widths,
+            self.bottom_left,
+            self.bottom_joint,
+            self.bottom_right,
+        )
+    }
+
+    fn line(
+        &self,
+        widths: &[usize],
+        left: char,
+        joint: char,
+        right: char,
+    ) -> String {
+        let mut line = String::from(left);
+        for (index, width) in widths.iter().enumerate() {
+            if index > 0 {
+                line.push(joint);
+            }
+            line.extend(std::iter::repeat_n(self.horizontal, *width));
+        }
+        line.push(right);
+        line
+    }
+}
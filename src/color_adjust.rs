@@ -0,0 +1,237 @@
+//! Color manipulations on [RGBColor],
+//! such as mixing, lightening, darkening and saturating.
+//!
+//! Except [mix](RGBColor::mix) (which can choose the [Interpolation] space)
+//! and [invert](RGBColor::invert), all manipulations are done
+//! in the perceptual [Oklch] space, so that the same ratio
+//! will have a similar visual effect on different colors.
+//!
+//! ```rust
+//! use terminal_font::{color_space::Oklch, custom_color::RGBColor};
+//!
+//! let brand = RGBColor::from(0x1e90ff);
+//! let hover = brand.lighten(0.2);
+//! let dim = brand.darken(0.3).desaturate(0.5);
+//! assert!(Oklch::from(hover).l > Oklch::from(brand).l);
+//! assert!(Oklch::from(dim).c < Oklch::from(brand).c);
+//! ```
+
+use crate::{
+    color_space::{from_linear, to_linear, Oklab, Oklch},
+    custom_color::RGBColor,
+};
+
+/// The color space used to interpolate between two colors.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Interpolation {
+    /// Interpolate the raw sRGB channels, the naive way,
+    /// usually a bit darker in the middle.
+    Srgb,
+
+    /// Interpolate in linear light, physically correct mixing.
+    LinearRgb,
+
+    /// Interpolate in [Oklab], perceptually uniform.
+    #[default]
+    Oklab,
+
+    /// Interpolate lightness, chroma and hue in [Oklch]
+    /// along the shorter hue arc, keeps the middle colors vivid.
+    Oklch,
+}
+
+fn lerp(from: f64, to: f64, ratio: f64) -> f64 {
+    from + (to - from) * ratio
+}
+
+impl RGBColor {
+    /// Mix current color with another one.
+    /// The `ratio` (clamped into `0.0..=1.0`) is the weight of `other`,
+    /// `0.0` returns current color and `1.0` returns `other`.
+    ///
+    /// ```rust
+    /// use terminal_font::{color_adjust::*, custom_color::RGBColor};
+    ///
+    /// let black = RGBColor::from(0x000000);
+    /// let white = RGBColor::from(0xffffff);
+    /// let srgb = black.mix(white, 0.5, Interpolation::Srgb);
+    /// let linear = black.mix(white, 0.5, Interpolation::LinearRgb);
+    /// assert_eq!(srgb, RGBColor::from(0x808080));
+    /// assert_eq!(linear, RGBColor::from(0xbcbcbc));
+    /// assert_eq!(black.mix(white, 0.0, Interpolation::Oklab), black);
+    /// assert_eq!(black.mix(white, 1.0, Interpolation::Oklch), white);
+    /// ```
+    pub fn mix(
+        self,
+        other: RGBColor,
+        ratio: f64,
+        space: Interpolation,
+    ) -> RGBColor {
+        let ratio = ratio.clamp(0.0, 1.0);
+        match space {
+            Interpolation::Srgb => {
+                let channel = |from: u8, to: u8| {
+                    lerp(from as f64, to as f64, ratio).round() as u8
+                };
+                RGBColor {
+                    r: channel(self.r, other.r),
+                    g: channel(self.g, other.g),
+                    b: channel(self.b, other.b),
+                }
+            }
+            Interpolation::LinearRgb => {
+                let channel = |from: u8, to: u8| {
+                    from_linear(lerp(to_linear(from), to_linear(to), ratio))
+                };
+                RGBColor {
+                    r: channel(self.r, other.r),
+                    g: channel(self.g, other.g),
+                    b: channel(self.b, other.b),
+                }
+            }
+            Interpolation::Oklab => {
+                let (from, to) = (Oklab::from(self), Oklab::from(other));
+                RGBColor::from(Oklab {
+                    l: lerp(from.l, to.l, ratio),
+                    a: lerp(from.a, to.a, ratio),
+                    b: lerp(from.b, to.b, ratio),
+                })
+            }
+            Interpolation::Oklch => {
+                let (mut from, mut to) =
+                    (Oklch::from(self), Oklch::from(other));
+                // Achromatic colors have no meaningful hue.
+                const ACHROMATIC: f64 = 1e-4;
+                if from.c < ACHROMATIC {
+                    from.h = to.h;
+                }
+                if to.c < ACHROMATIC {
+                    to.h = from.h;
+                }
+                let mut delta = to.h - from.h;
+                if delta > 180.0 {
+                    delta -= 360.0;
+                } else if delta < -180.0 {
+                    delta += 360.0;
+                }
+                RGBColor::from(Oklch {
+                    l: lerp(from.l, to.l, ratio),
+                    c: lerp(from.c, to.c, ratio),
+                    h: from.h + delta * ratio,
+                })
+            }
+        }
+    }
+
+    /// Adjust the color in [Oklch] space,
+    /// the result will be mapped back into the sRGB gamut.
+    fn adjust(self, adjust: impl FnOnce(&mut Oklch)) -> RGBColor {
+        let mut color = Oklch::from(self);
+        adjust(&mut color);
+        RGBColor::from(color)
+    }
+
+    /// Move the lightness towards white by the `ratio`
+    /// (clamped into `0.0..=1.0`), `1.0` means pure white.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// let color = RGBColor::from(0x1e90ff);
+    /// assert_eq!(color.lighten(0.0), color);
+    /// assert_eq!(color.lighten(1.0), RGBColor::from(0xffffff));
+    /// ```
+    pub fn lighten(self, ratio: f64) -> RGBColor {
+        let ratio = ratio.clamp(0.0, 1.0);
+        self.adjust(|color| color.l += (1.0 - color.l) * ratio)
+    }
+
+    /// Move the lightness towards black by the `ratio`
+    /// (clamped into `0.0..=1.0`), `1.0` means pure black.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// let color = RGBColor::from(0x1e90ff);
+    /// assert_eq!(color.darken(0.0), color);
+    /// assert_eq!(color.darken(1.0), RGBColor::from(0x000000));
+    /// ```
+    pub fn darken(self, ratio: f64) -> RGBColor {
+        let ratio = ratio.clamp(0.0, 1.0);
+        self.adjust(|color| color.l *= 1.0 - ratio)
+    }
+
+    /// Increase the chroma by the `ratio` (clamped into `0.0..=10.0`),
+    /// for example `0.5` means 1.5 times the original chroma.
+    /// The result is limited by the sRGB gamut.
+    ///
+    /// ```rust
+    /// use terminal_font::{color_space::Oklch, custom_color::RGBColor};
+    ///
+    /// let color = RGBColor::from(0x6b8e9f);
+    /// let saturated = color.saturate(0.5);
+    /// assert!(Oklch::from(saturated).c > Oklch::from(color).c);
+    /// assert_eq!(color.saturate(f64::INFINITY), color.saturate(10.0));
+    /// ```
+    pub fn saturate(self, ratio: f64) -> RGBColor {
+        let ratio = ratio.clamp(0.0, 10.0);
+        self.adjust(|color| color.c *= 1.0 + ratio)
+    }
+
+    /// Decrease the chroma by the `ratio` (clamped into `0.0..=1.0`),
+    /// `1.0` means the same as [grayscale](RGBColor::grayscale).
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// let color = RGBColor::from(0x1e90ff);
+    /// assert_eq!(color.desaturate(1.0), color.grayscale());
+    /// ```
+    pub fn desaturate(self, ratio: f64) -> RGBColor {
+        let ratio = ratio.clamp(0.0, 1.0);
+        self.adjust(|color| color.c *= 1.0 - ratio)
+    }
+
+    /// Remove the chroma, keeping the perceptual lightness.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// let gray = RGBColor::from(0x1e90ff).grayscale();
+    /// assert!(gray.r == gray.g && gray.g == gray.b);
+    /// let gray = RGBColor::from(0x808080);
+    /// assert_eq!(gray.grayscale(), gray);
+    /// ```
+    pub fn grayscale(self) -> RGBColor {
+        self.adjust(|color| color.c = 0.0)
+    }
+
+    /// Rotate the hue by 180 degrees,
+    /// keeping the perceptual lightness and chroma.
+    ///
+    /// ```rust
+    /// use terminal_font::{color_space::Oklch, custom_color::RGBColor};
+    ///
+    /// let color = RGBColor::from(0x1e90ff);
+    /// let complement = Oklch::from(color.complement());
+    /// let distance = complement.h - Oklch::from(color).h;
+    /// assert!((distance.rem_euclid(360.0) - 180.0).abs() < 1.0);
+    /// ```
+    pub fn complement(self) -> RGBColor {
+        self.adjust(|color| color.h = (color.h + 180.0).rem_euclid(360.0))
+    }
+
+    /// Invert each channel in sRGB, like a photo negative.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    /// assert_eq!(RGBColor::from(0x1e90ff).invert(), RGBColor::from(0xe16f00));
+    /// ```
+    pub fn invert(self) -> RGBColor {
+        RGBColor {
+            r: 255 - self.r,
+            g: 255 - self.g,
+            b: 255 - self.b,
+        }
+    }
+}
@@ -0,0 +1,381 @@
+//! Other color spaces that can be converted from and into [RGBColor],
+//! including [Hsl], [Hsv], [Oklab] and [Oklch].
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::{color_space::*, custom_color::*};
+//!
+//! let color = RGBColor::from(Hsl::new(210.0, 0.6, 0.5));
+//! assert_eq!(color, RGBColor::from((51, 128, 204)));
+//! assert_eq!("hello".simple_fg(color), "\x1b[38;2;51;128;204mhello\x1b[39m");
+//! ```
+//!
+//! ## Gamut clamping
+//!
+//! Not all colors in those spaces can be represented in sRGB.
+//! Out of range components of [Hsl] and [Hsv] are clamped
+//! (and the hue is wrapped into `0..360`).
+//! Out of gamut [Oklch] colors will keep their lightness and hue,
+//! and reduce the chroma until they fit into sRGB.
+
+use crate::custom_color::RGBColor;
+
+/// Convert an sRGB channel (`0..=255`) into linear light (`0.0..=1.0`).
+pub(crate) fn to_linear(channel: u8) -> f64 {
+    let value = channel as f64 / 255.0;
+    match value <= 0.04045 {
+        true => value / 12.92,
+        false => ((value + 0.055) / 1.055).powf(2.4),
+    }
+}
+
+/// Convert a linear light value into sRGB channel, clamped into range.
+pub(crate) fn from_linear(value: f64) -> u8 {
+    let value = value.clamp(0.0, 1.0);
+    let value = match value <= 0.0031308 {
+        true => value * 12.92,
+        false => 1.055 * value.powf(1.0 / 2.4) - 0.055,
+    };
+    to_channel(value)
+}
+
+/// Convert a normalized value (`0.0..=1.0`) into a channel, clamped into range.
+fn to_channel(value: f64) -> u8 {
+    (value.clamp(0.0, 1.0) * 255.0).round() as u8
+}
+
+/// Normalize hue into `0.0..360.0`.
+fn wrap_hue(hue: f64) -> f64 {
+    match hue.is_finite() {
+        true => hue.rem_euclid(360.0),
+        false => 0.0,
+    }
+}
+
+/// Hue (degrees), maximum and minimum channels of an RGB color,
+/// all channels normalized into `0.0..=1.0`.
+fn hue_max_min(color: RGBColor) -> (f64, f64, f64) {
+    let (r, g, b) = (
+        color.r as f64 / 255.0,
+        color.g as f64 / 255.0,
+        color.b as f64 / 255.0,
+    );
+    let max = r.max(g).max(b);
+    let min = r.min(g).min(b);
+    let delta = max - min;
+    let hue = if delta == 0.0 {
+        0.0
+    } else if max == r {
+        60.0 * ((g - b) / delta).rem_euclid(6.0)
+    } else if max == g {
+        60.0 * ((b - r) / delta + 2.0)
+    } else {
+        60.0 * ((r - g) / delta + 4.0)
+    };
+    (hue, max, min)
+}
+
+/// Build an RGB color from hue (degrees), chroma and the offset to add,
+/// the common part of HSL and HSV conversions.
+fn from_hue_chroma(hue: f64, chroma: f64, offset: f64) -> RGBColor {
+    let sector = wrap_hue(hue) / 60.0;
+    let x = chroma * (1.0 - (sector % 2.0 - 1.0).abs());
+    let (r, g, b) = match sector as u8 {
+        0 => (chroma, x, 0.0),
+        1 => (x, chroma, 0.0),
+        2 => (0.0, chroma, x),
+        3 => (0.0, x, chroma),
+        4 => (x, 0.0, chroma),
+        _ => (chroma, 0.0, x),
+    };
+    RGBColor {
+        r: to_channel(r + offset),
+        g: to_channel(g + offset),
+        b: to_channel(b + offset),
+    }
+}
+
+/// Hue, saturation and lightness.
+/// Hue is in degrees (`0.0..360.0`),
+/// saturation and lightness are in `0.0..=1.0`.
+///
+/// ```rust
+/// use terminal_font::{color_space::Hsl, custom_color::RGBColor};
+///
+/// let red = RGBColor::from(Hsl::new(0.0, 1.0, 0.5));
+/// assert_eq!(red, RGBColor::from(0xff0000));
+/// let clamped = RGBColor::from(Hsl::new(480.0, 2.0, 0.5));
+/// assert_eq!(clamped, RGBColor::from(0x00ff00));
+///
+/// let hsl = Hsl::from(RGBColor::from(0x1e90ff));
+/// assert_eq!(hsl.h.round(), 210.0);
+/// assert_eq!((hsl.s * 100.0).round(), 100.0);
+/// assert_eq!((hsl.l * 100.0).round(), 56.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsl {
+    pub h: f64,
+    pub s: f64,
+    pub l: f64,
+}
+
+impl Hsl {
+    pub fn new(h: f64, s: f64, l: f64) -> Self {
+        Hsl { h, s, l }
+    }
+}
+
+impl From<RGBColor> for Hsl {
+    fn from(value: RGBColor) -> Self {
+        let (h, max, min) = hue_max_min(value);
+        let l = (max + min) / 2.0;
+        let s = match max == min {
+            true => 0.0,
+            false => (max - min) / (1.0 - (2.0 * l - 1.0).abs()),
+        };
+        Hsl { h, s, l }
+    }
+}
+
+impl From<Hsl> for RGBColor {
+    fn from(value: Hsl) -> Self {
+        let s = value.s.clamp(0.0, 1.0);
+        let l = value.l.clamp(0.0, 1.0);
+        let chroma = (1.0 - (2.0 * l - 1.0).abs()) * s;
+        from_hue_chroma(value.h, chroma, l - chroma / 2.0)
+    }
+}
+
+/// Hue, saturation and value (also known as HSB).
+/// Hue is in degrees (`0.0..360.0`),
+/// saturation and value are in `0.0..=1.0`.
+///
+/// ```rust
+/// use terminal_font::{color_space::Hsv, custom_color::RGBColor};
+///
+/// let blue = RGBColor::from(Hsv::new(240.0, 1.0, 1.0));
+/// assert_eq!(blue, RGBColor::from(0x0000ff));
+/// let gray = RGBColor::from(Hsv::new(0.0, 0.0, 0.5));
+/// assert_eq!(gray, RGBColor::from(0x808080));
+///
+/// let hsv = Hsv::from(RGBColor::from(0x1e90ff));
+/// assert_eq!(hsv.h.round(), 210.0);
+/// assert_eq!((hsv.s * 100.0).round(), 88.0);
+/// assert_eq!(hsv.v, 1.0);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Hsv {
+    pub h: f64,
+    pub s: f64,
+    pub v: f64,
+}
+
+impl Hsv {
+    pub fn new(h: f64, s: f64, v: f64) -> Self {
+        Hsv { h, s, v }
+    }
+}
+
+impl From<RGBColor> for Hsv {
+    fn from(value: RGBColor) -> Self {
+        let (h, max, min) = hue_max_min(value);
+        let s = match max == 0.0 {
+            true => 0.0,
+            false => (max - min) / max,
+        };
+        Hsv { h, s, v: max }
+    }
+}
+
+impl From<Hsv> for RGBColor {
+    fn from(value: Hsv) -> Self {
+        let s = value.s.clamp(0.0, 1.0);
+        let v = value.v.clamp(0.0, 1.0);
+        let chroma = v * s;
+        from_hue_chroma(value.h, chroma, v - chroma)
+    }
+}
+
+/// The perceptual OKLab color space.
+/// Lightness `l` is in `0.0..=1.0`,
+/// `a` and `b` are usually in `-0.4..=0.4`.
+///
+/// ```rust
+/// use terminal_font::{color_space::Oklab, custom_color::RGBColor};
+///
+/// let white = Oklab::from(RGBColor::from(0xffffff));
+/// assert!((white.l - 1.0).abs() < 1e-6);
+/// assert!(white.a.abs() < 1e-6 && white.b.abs() < 1e-6);
+///
+/// let color = RGBColor::from(0x1e90ff);
+/// assert_eq!(RGBColor::from(Oklab::from(color)), color);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklab {
+    pub l: f64,
+    pub a: f64,
+    pub b: f64,
+}
+
+impl Oklab {
+    pub fn new(l: f64, a: f64, b: f64) -> Self {
+        Oklab { l, a, b }
+    }
+
+    /// Convert into linear sRGB, the result might be out of `0.0..=1.0`.
+    pub(crate) fn to_linear_rgb(self) -> [f64; 3] {
+        let l =
+            (self.l + 0.3963377774 * self.a + 0.2158037573 * self.b).powi(3);
+        let m =
+            (self.l - 0.1055613458 * self.a - 0.0638541728 * self.b).powi(3);
+        let s =
+            (self.l - 0.0894841775 * self.a - 1.2914855480 * self.b).powi(3);
+        [
+            4.0767416621 * l - 3.3077115913 * m + 0.2309699292 * s,
+            -1.2684380046 * l + 2.6097574011 * m - 0.3413193965 * s,
+            -0.0041960863 * l - 0.7034186147 * m + 1.7076147010 * s,
+        ]
+    }
+
+    /// Convert from linear sRGB.
+    pub(crate) fn from_linear_rgb([r, g, b]: [f64; 3]) -> Self {
+        let l = (0.4122214708 * r + 0.5363325363 * g + 0.0514459929 * b).cbrt();
+        let m = (0.2119034982 * r + 0.6806995451 * g + 0.1073969566 * b).cbrt();
+        let s = (0.0883024619 * r + 0.2817188376 * g + 0.6299787005 * b).cbrt();
+        Oklab {
+            l: 0.2104542553 * l + 0.7936177850 * m - 0.0040720468 * s,
+            a: 1.9779984951 * l - 2.4285922050 * m + 0.4505937099 * s,
+            b: 0.0259040371 * l + 0.7827717662 * m - 0.8086757660 * s,
+        }
+    }
+}
+
+impl From<RGBColor> for Oklab {
+    fn from(value: RGBColor) -> Self {
+        let linear =
+            [to_linear(value.r), to_linear(value.g), to_linear(value.b)];
+        Oklab::from_linear_rgb(linear)
+    }
+}
+
+impl From<Oklab> for RGBColor {
+    /// Out of gamut channels are clipped.
+    /// Convert with [Oklch] to keep the lightness and hue instead.
+    fn from(value: Oklab) -> Self {
+        let [r, g, b] = value.to_linear_rgb();
+        RGBColor {
+            r: from_linear(r),
+            g: from_linear(g),
+            b: from_linear(b),
+        }
+    }
+}
+
+impl From<Oklch> for Oklab {
+    fn from(value: Oklch) -> Self {
+        let hue = value.h.to_radians();
+        Oklab {
+            l: value.l,
+            a: value.c * hue.cos(),
+            b: value.c * hue.sin(),
+        }
+    }
+}
+
+impl From<Oklab> for Oklch {
+    fn from(value: Oklab) -> Self {
+        let c = (value.a * value.a + value.b * value.b).sqrt();
+        let h = match c < 1e-8 {
+            true => 0.0,
+            false => wrap_hue(value.b.atan2(value.a).to_degrees()),
+        };
+        Oklch { l: value.l, c, h }
+    }
+}
+
+/// The cylindrical form of [Oklab]: lightness, chroma and hue.
+/// Lightness is in `0.0..=1.0`, chroma is usually in `0.0..=0.4`,
+/// and hue is in degrees (`0.0..360.0`).
+///
+/// Out of gamut colors keep their lightness and hue,
+/// and reduce the chroma until they fit into sRGB:
+///
+/// ```rust
+/// use terminal_font::{color_space::Oklch, custom_color::RGBColor};
+///
+/// let color = RGBColor::from(Oklch::new(0.7, 0.4, 140.0));
+/// let back = Oklch::from(color);
+/// assert!((back.l - 0.7).abs() < 0.01);
+/// assert!((back.h - 140.0).abs() < 2.0);
+/// assert!(back.c < 0.4);
+///
+/// let white = RGBColor::from(Oklch::new(1.2, 0.1, 0.0));
+/// assert_eq!(white, RGBColor::from(0xffffff));
+/// let black = RGBColor::from(Oklch::new(0.0, 0.1, 0.0));
+/// assert_eq!(black, RGBColor::from(0x000000));
+///
+/// let infinite = RGBColor::from(Oklch::new(0.5, f64::INFINITY, 30.0));
+/// let bounded = RGBColor::from(Oklch::new(0.5, 0.5, 30.0));
+/// assert_eq!(infinite, bounded);
+///
+/// let color = RGBColor::from(0x1e90ff);
+/// assert_eq!(RGBColor::from(Oklch::from(color)), color);
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq)]
+pub struct Oklch {
+    pub l: f64,
+    pub c: f64,
+    pub h: f64,
+}
+
+impl Oklch {
+    pub fn new(l: f64, c: f64, h: f64) -> Self {
+        Oklch { l, c, h }
+    }
+}
+
+impl From<RGBColor> for Oklch {
+    fn from(value: RGBColor) -> Self {
+        Oklch::from(Oklab::from(value))
+    }
+}
+
+impl From<Oklch> for RGBColor {
+    fn from(value: Oklch) -> Self {
+        if value.l >= 1.0 {
+            return RGBColor {
+                r: 255,
+                g: 255,
+                b: 255,
+            };
+        }
+        if value.l <= 0.0 {
+            return RGBColor { r: 0, g: 0, b: 0 };
+        }
+
+        const EPSILON: f64 = 1e-4;
+        let in_gamut = |chroma: f64| {
+            let lab = Oklab::from(Oklch { c: chroma, ..value });
+            lab.to_linear_rgb()
+                .iter()
+                .all(|channel| (-EPSILON..=1.0 + EPSILON).contains(channel))
+        };
+
+        // Above the chroma of any sRGB color, also bounds infinite input.
+        const MAX_CHROMA: f64 = 0.5;
+        let mut chroma = value.c.clamp(0.0, MAX_CHROMA);
+        if !in_gamut(chroma) {
+            let (mut low, mut high) = (0.0, chroma);
+            while high - low > 1e-5 {
+                let middle = (low + high) / 2.0;
+                match in_gamut(middle) {
+                    true => low = middle,
+                    false => high = middle,
+                }
+            }
+            chroma = low;
+        }
+        RGBColor::from(Oklab::from(Oklch { c: chroma, ..value }))
+    }
+}
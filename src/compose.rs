@@ -0,0 +1,574 @@
+//! Composable [Style] value type.
+//!
+//! Chaining decorations such as `"x".simple_red().simple_bold()`
+//! will generate a prefix and a suffix (and a new [String]) for each call.
+//! A [Style] collects the foreground, the background and the [Attributes],
+//! and applies them only once with a single combined escape sequence:
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::compose::*;
+//!
+//! let style = Style::new().bold().italic().fg(Color::Red);
+//! assert_eq!(style.prefix(), "\x1b[1;3;31m");
+//! assert_eq!(style.suffix(), "\x1b[22;23;39m");
+//! assert_eq!(style.simple_apply("x"), "\x1b[1;3;31mx\x1b[22;23;39m");
+//! ```
+
+use std::{
+    fmt,
+    ops::{BitOr, BitOrAssign},
+};
+
+use crate::{
+    custom::*,
+    custom_color::RGBColor,
+    decorate::Decorate,
+    detect::{color_depth, ColorDepth},
+    escape::{ExtendedColor, SgrAttribute, SgrParams},
+    render::Render,
+};
+
+#[allow(unused_imports)] // Docs only.
+use crate::decorate;
+
+/// Colors that can be used as foreground or background of a [Style],
+/// including the 16 basic colors (as in the [foreground](crate::foreground)
+/// and [background](crate::background) mods),
+/// the 256 color codes and the RGB colors.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Color {
+    Black,
+    Red,
+    Green,
+    Yellow,
+    Blue,
+    Magenta,
+    Cyan,
+    White,
+    BrightBlack,
+    BrightRed,
+    BrightGreen,
+    BrightYellow,
+    BrightBlue,
+    BrightMagenta,
+    BrightCyan,
+    BrightWhite,
+    Code(u8),
+    Rgb(RGBColor),
+}
+
+impl Color {
+    /// The 16 basic colors, in the order of their escape codes.
+    pub const BASIC: [Color; 16] = [
+        Color::Black,
+        Color::Red,
+        Color::Green,
+        Color::Yellow,
+        Color::Blue,
+        Color::Magenta,
+        Color::Cyan,
+        Color::White,
+        Color::BrightBlack,
+        Color::BrightRed,
+        Color::BrightGreen,
+        Color::BrightYellow,
+        Color::BrightBlue,
+        Color::BrightMagenta,
+        Color::BrightCyan,
+        Color::BrightWhite,
+    ];
+
+    /// Index inside [Color::BASIC], or [None] for extended colors.
+    pub fn basic_index(&self) -> Option<u8> {
+        Color::BASIC
+            .iter()
+            .position(|color| color == self)
+            .map(|index| index as u8)
+    }
+
+    /// The RGB value of the color.
+    /// Basic colors and color codes are converted
+    /// according to the xterm [palette](crate::palette).
+    ///
+    /// ```rust
+    /// use terminal_font::{compose::Color, custom_color::RGBColor};
+    /// assert_eq!(Color::Red.to_rgb(), RGBColor::from((205, 0, 0)));
+    /// assert_eq!(Color::Code(196).to_rgb(), RGBColor::from((255, 0, 0)));
+    /// ```
+    pub fn to_rgb(&self) -> RGBColor {
+        match (self, self.basic_index()) {
+            (Color::Rgb(color), _) => *color,
+            (Color::Code(code), _) => RGBColor::from_code(*code),
+            (_, Some(index)) => RGBColor::from_code(index),
+            _ => unreachable!(),
+        }
+    }
+
+    /// Convert the color to fit the target color depth.
+    /// RGB colors will be converted to the nearest 256 color code
+    /// ([RGBColor::to_ansi256]) or the nearest basic color
+    /// ([RGBColor::to_ansi16]), and color codes will be converted
+    /// to the nearest basic color. Other colors are kept unchanged.
+    ///
+    /// ```rust
+    /// use terminal_font::{compose::Color, detect::ColorDepth};
+    ///
+    /// let color = Color::from((255, 0, 0));
+    /// assert_eq!(color.downsample(ColorDepth::TrueColor), color);
+    /// assert_eq!(color.downsample(ColorDepth::Ansi256), Color::Code(196));
+    /// assert_eq!(color.downsample(ColorDepth::Ansi16), Color::BrightRed);
+    ///
+    /// let code = Color::Code(196);
+    /// assert_eq!(code.downsample(ColorDepth::Ansi256), code);
+    /// assert_eq!(code.downsample(ColorDepth::Ansi16), Color::BrightRed);
+    /// assert_eq!(Color::Code(4).downsample(ColorDepth::Ansi16), Color::Blue);
+    /// ```
+    pub fn downsample(self, depth: ColorDepth) -> Color {
+        match (self, depth) {
+            (Color::Rgb(color), ColorDepth::Ansi256) => {
+                Color::Code(color.to_ansi256())
+            }
+            (
+                Color::Code(code @ 0..=15),
+                ColorDepth::Ansi16 | ColorDepth::None,
+            ) => Color::BASIC[code as usize],
+            (
+                Color::Rgb(_) | Color::Code(_),
+                ColorDepth::Ansi16 | ColorDepth::None,
+            ) => Color::BASIC[self.to_rgb().to_ansi16() as usize],
+            (color, _) => color,
+        }
+    }
+
+    /// SGR parameters of the color when used as foreground,
+    /// such as `31` or `38;5;123` or `38;2;1;2;3`.
+    ///
+    /// ```rust
+    /// use terminal_font::compose::Color;
+    /// assert_eq!(Color::Red.foreground_params(), "31");
+    /// assert_eq!(Color::BrightRed.foreground_params(), "91");
+    /// assert_eq!(Color::Code(123).foreground_params(), "38;5;123");
+    /// assert_eq!(Color::from((1, 2, 3)).foreground_params(), "38;2;1;2;3");
+    /// ```
+    pub fn foreground_params(&self) -> String {
+        let mut handler = String::new();
+        self.write_params(&mut handler, false).unwrap();
+        handler
+    }
+
+    /// SGR parameters of the color when used as background,
+    /// such as `41` or `48;5;123` or `48;2;1;2;3`.
+    ///
+    /// ```rust
+    /// use terminal_font::compose::Color;
+    /// assert_eq!(Color::Red.background_params(), "41");
+    /// assert_eq!(Color::BrightRed.background_params(), "101");
+    /// assert_eq!(Color::Code(123).background_params(), "48;5;123");
+    /// assert_eq!(Color::from((1, 2, 3)).background_params(), "48;2;1;2;3");
+    /// ```
+    pub fn background_params(&self) -> String {
+        let mut handler = String::new();
+        self.write_params(&mut handler, true).unwrap();
+        handler
+    }
+
+    /// Escape sequence to decorate foreground with current color.
+    pub fn foreground(&self) -> String {
+        format!("\x1b[{}m", self.foreground_params())
+    }
+
+    /// Escape sequence to decorate background with current color.
+    pub fn background(&self) -> String {
+        format!("\x1b[{}m", self.background_params())
+    }
+
+    /// Write the SGR parameters directly into the writer,
+    /// without allocating a new [String].
+    pub(crate) fn write_params<W: fmt::Write>(
+        &self,
+        w: &mut W,
+        background: bool,
+    ) -> fmt::Result {
+        let (base, extended) = match background {
+            true => (40, BACKGROUND),
+            false => (30, FOREGROUND),
+        };
+        match (self, self.basic_index()) {
+            (_, Some(index @ 0..=7)) => write!(w, "{}", base + index),
+            (_, Some(index)) => write!(w, "{}", base + 60 + index - 8),
+            (Color::Code(code), _) => {
+                write!(w, "{extended};{CODE_MODE};{code}")
+            }
+            (Color::Rgb(c), _) => {
+                write!(w, "{extended};{RGB_MODE};{};{};{}", c.r, c.g, c.b)
+            }
+            _ => unreachable!(),
+        }
+    }
+}
+
+impl From<u8> for Color {
+    /// Generate [Color::Code] from a 256 color code.
+    fn from(value: u8) -> Self {
+        Color::Code(value)
+    }
+}
+
+impl From<RGBColor> for Color {
+    fn from(value: RGBColor) -> Self {
+        Color::Rgb(value)
+    }
+}
+
+impl From<ExtendedColor> for Color {
+    fn from(value: ExtendedColor) -> Self {
+        match value {
+            ExtendedColor::Code(code) => Color::Code(code),
+            ExtendedColor::Rgb(color) => Color::Rgb(color),
+        }
+    }
+}
+
+impl From<(u8, u8, u8)> for Color {
+    fn from(value: (u8, u8, u8)) -> Self {
+        Color::Rgb(RGBColor::from(value))
+    }
+}
+
+/// A bitset of the font styles inside the [style](crate::style) mod.
+///
+/// ```rust
+/// use terminal_font::compose::Attributes;
+///
+/// let attributes = Attributes::BOLD | Attributes::ITALIC;
+/// assert!(attributes.contains(Attributes::BOLD));
+/// assert!(!attributes.contains(Attributes::BOLD | Attributes::INVERSE));
+/// assert_eq!(attributes.codes().collect::<Vec<_>>(), vec![1, 3]);
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Attributes(u16);
+
+impl Attributes {
+    pub const NONE: Attributes = Attributes(0);
+    pub const BOLD: Attributes = Attributes(1 << 0);
+    pub const FAINT: Attributes = Attributes(1 << 1);
+    pub const ITALIC: Attributes = Attributes(1 << 2);
+    pub const UNDERLINE: Attributes = Attributes(1 << 3);
+    pub const BLINK: Attributes = Attributes(1 << 4);
+    pub const BLINK_FAST: Attributes = Attributes(1 << 5);
+    pub const INVERSE: Attributes = Attributes(1 << 6);
+    pub const CONCEAL: Attributes = Attributes(1 << 7);
+    pub const STRIKETHROUGH: Attributes = Attributes(1 << 8);
+    pub const DOUBLE_UNDERLINE: Attributes = Attributes(1 << 9);
+
+    /// Each attribute with its escape code and its cancel code.
+    pub const TABLE: [(Attributes, u8, u8); 10] = [
+        (Attributes::BOLD, 1, 22),
+        (Attributes::FAINT, 2, 22),
+        (Attributes::ITALIC, 3, 23),
+        (Attributes::UNDERLINE, 4, 24),
+        (Attributes::BLINK, 5, 25),
+        (Attributes::BLINK_FAST, 6, 25),
+        (Attributes::INVERSE, 7, 27),
+        (Attributes::CONCEAL, 8, 28),
+        (Attributes::STRIKETHROUGH, 9, 29),
+        (Attributes::DOUBLE_UNDERLINE, 21, 24),
+    ];
+
+    pub const fn is_empty(self) -> bool {
+        self.0 == 0
+    }
+
+    /// Whether all attributes in `other` are also inside `self`.
+    pub const fn contains(self, other: Attributes) -> bool {
+        self.0 & other.0 == other.0
+    }
+
+    pub const fn union(self, other: Attributes) -> Attributes {
+        Attributes(self.0 | other.0)
+    }
+
+    /// Attributes inside `self` but not inside `other`.
+    pub const fn difference(self, other: Attributes) -> Attributes {
+        Attributes(self.0 & !other.0)
+    }
+
+    /// Escape codes of the attributes, in ascending order.
+    pub fn codes(self) -> impl Iterator<Item = u8> {
+        Attributes::TABLE
+            .into_iter()
+            .filter(move |(attribute, _, _)| self.contains(*attribute))
+            .map(|(_, code, _)| code)
+    }
+
+    /// Cancel codes of the attributes, in ascending order and deduplicated.
+    /// For example, bold and faint share the same cancel code `22`.
+    pub fn cancel_codes(self) -> impl Iterator<Item = u8> {
+        let mut codes: Vec<u8> = Attributes::TABLE
+            .into_iter()
+            .filter(|(attribute, _, _)| self.contains(*attribute))
+            .map(|(_, _, cancel)| cancel)
+            .collect();
+        codes.sort_unstable();
+        codes.dedup();
+        codes.into_iter()
+    }
+}
+
+impl BitOr for Attributes {
+    type Output = Attributes;
+
+    fn bitor(self, rhs: Attributes) -> Attributes {
+        self.union(rhs)
+    }
+}
+
+impl BitOrAssign for Attributes {
+    fn bitor_assign(&mut self, rhs: Attributes) {
+        *self = self.union(rhs);
+    }
+}
+
+/// Composable style with foreground, background and [Attributes].
+/// Build it up with the chained methods, [merge](Style::merge) with others,
+/// and apply it once with a single combined escape sequence.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::compose::*;
+///
+/// let base = Style::new().fg(Color::Red).underline();
+/// let emphasis = Style::new().bold().fg(Color::Code(208));
+/// let merged = base.merge(emphasis);
+/// assert_eq!(merged.prefix(), "\x1b[1;4;38;5;208m");
+/// assert_eq!(merged.suffix(), "\x1b[22;24;39m");
+/// assert_eq!(Style::new().prefix(), "");
+/// ```
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Style {
+    pub foreground: Option<Color>,
+    pub background: Option<Color>,
+    pub attributes: Attributes,
+}
+
+impl Style {
+    /// A plain style without any decoration.
+    pub const fn new() -> Self {
+        Style {
+            foreground: None,
+            background: None,
+            attributes: Attributes::NONE,
+        }
+    }
+
+    pub fn fg<T: Into<Color>>(mut self, color: T) -> Self {
+        self.foreground = Some(color.into());
+        self
+    }
+
+    pub fn bg<T: Into<Color>>(mut self, color: T) -> Self {
+        self.background = Some(color.into());
+        self
+    }
+
+    /// Add the given attributes.
+    pub const fn attributes(mut self, attributes: Attributes) -> Self {
+        self.attributes = self.attributes.union(attributes);
+        self
+    }
+
+    pub const fn bold(self) -> Self {
+        self.attributes(Attributes::BOLD)
+    }
+
+    pub const fn faint(self) -> Self {
+        self.attributes(Attributes::FAINT)
+    }
+
+    pub const fn italic(self) -> Self {
+        self.attributes(Attributes::ITALIC)
+    }
+
+    pub const fn underline(self) -> Self {
+        self.attributes(Attributes::UNDERLINE)
+    }
+
+    pub const fn blink(self) -> Self {
+        self.attributes(Attributes::BLINK)
+    }
+
+    pub const fn blink_fast(self) -> Self {
+        self.attributes(Attributes::BLINK_FAST)
+    }
+
+    pub const fn inverse(self) -> Self {
+        self.attributes(Attributes::INVERSE)
+    }
+
+    pub const fn conceal(self) -> Self {
+        self.attributes(Attributes::CONCEAL)
+    }
+
+    pub const fn strikethrough(self) -> Self {
+        self.attributes(Attributes::STRIKETHROUGH)
+    }
+
+    pub const fn double_underline(self) -> Self {
+        self.attributes(Attributes::DOUBLE_UNDERLINE)
+    }
+
+    /// Whether there's no decoration at all.
+    pub const fn is_plain(&self) -> bool {
+        self.foreground.is_none()
+            && self.background.is_none()
+            && self.attributes.is_empty()
+    }
+
+    /// Merge another style into current one.
+    /// Attributes are united,
+    /// and colors of `other` will override current ones if specified.
+    pub fn merge(self, other: Style) -> Self {
+        Style {
+            foreground: other.foreground.or(self.foreground),
+            background: other.background.or(self.background),
+            attributes: self.attributes.union(other.attributes),
+        }
+    }
+
+    /// The single combined escape sequence to enable the style,
+    /// or an empty string if the style [is_plain](Style::is_plain).
+    /// RGB colors will be downsampled ([Color::downsample])
+    /// according to the global color depth.
+    pub fn prefix(&self) -> String {
+        let mut params: Vec<String> = self
+            .attributes
+            .codes()
+            .map(|code| code.to_string())
+            .collect();
+        let depth = color_depth();
+        params.extend(
+            self.foreground
+                .map(|c| c.downsample(depth).foreground_params()),
+        );
+        params.extend(
+            self.background
+                .map(|c| c.downsample(depth).background_params()),
+        );
+        sgr(params)
+    }
+
+    /// The single combined escape sequence
+    /// with the minimal cancel codes to disable the style,
+    /// or an empty string if the style [is_plain](Style::is_plain).
+    pub fn suffix(&self) -> String {
+        let mut params: Vec<String> = self
+            .attributes
+            .cancel_codes()
+            .map(|code| code.to_string())
+            .collect();
+        if self.foreground.is_some() {
+            params.push("39".to_string());
+        }
+        if self.background.is_some() {
+            params.push("49".to_string());
+        }
+        sgr(params)
+    }
+
+    /// Apply the style by simply wrapping the prefix and the suffix.
+    /// See [decorate] about what does `simple` name prefix means.
+    pub fn simple_apply<T: AsRef<str>>(&self, raw: T) -> String {
+        raw.wrap_escape(self.prefix(), self.suffix())
+    }
+
+    /// Apply the style safely, the style will be recovered
+    /// after inner escape sequences that cancel it.
+    /// See the [render](crate::render) mod for more details.
+    ///
+    /// ```rust
+    /// # use terminal_font::detect::{set_color_depth, ColorDepth};
+    /// # set_color_depth(ColorDepth::TrueColor);
+    /// use terminal_font::{compose::*, decorate::*};
+    ///
+    /// let style = Style::new().bold().fg(Color::Red);
+    /// let raw = format!("a{}c", "b".simple_faint());
+    /// assert_eq!(
+    ///     style.apply(raw),
+    ///     "\x1b[1;31ma\x1b[2mb\x1b[22m\x1b[1;31mc\x1b[22;39m",
+    /// );
+    /// ```
+    pub fn apply<T: AsRef<str>>(&self, raw: T) -> String {
+        raw.render(self.prefix(), self.suffix())
+    }
+
+    /// Update the style as if the SGR attribute is received by a terminal,
+    /// to track the active style at some position of a decorated string.
+    /// Unsupported attributes (such as the underline color) are ignored.
+    ///
+    /// ```rust
+    /// use terminal_font::{compose::*, escape::*};
+    ///
+    /// let mut style = Style::new();
+    /// for token in tokenize("\x1b[1;31mbold red\x1b[22;48;5;4m") {
+    ///     if let Token::Sgr { params, .. } = token {
+    ///         style.update_sgr(params);
+    ///     }
+    /// }
+    /// assert_eq!(style, Style::new().fg(Color::Red).bg(Color::Code(4)));
+    /// style.update(SgrAttribute::Code(0));
+    /// assert!(style.is_plain());
+    /// ```
+    pub fn update(&mut self, attribute: SgrAttribute) {
+        let code = match attribute {
+            SgrAttribute::Code(code) => code,
+            SgrAttribute::Foreground(color) => {
+                self.foreground = Some(color.into());
+                return;
+            }
+            SgrAttribute::Background(color) => {
+                self.background = Some(color.into());
+                return;
+            }
+            SgrAttribute::Underline(_) => return,
+        };
+        let basic = |index: u16| Some(Color::BASIC[index as usize]);
+        match code {
+            0 => *self = Style::new(),
+            30..=37 => self.foreground = basic(code - 30),
+            90..=97 => self.foreground = basic(code - 90 + 8),
+            39 => self.foreground = None,
+            40..=47 => self.background = basic(code - 40),
+            100..=107 => self.background = basic(code - 100 + 8),
+            49 => self.background = None,
+            code => {
+                for (attribute, enable, cancel) in Attributes::TABLE {
+                    if code == enable as u16 {
+                        self.attributes |= attribute;
+                    } else if code == cancel as u16 {
+                        self.attributes = self.attributes.difference(attribute);
+                    }
+                }
+            }
+        }
+    }
+
+    /// Update the style with all the attributes of an SGR escape sequence,
+    /// see [Style::update].
+    pub fn update_sgr(&mut self, params: SgrParams) {
+        params
+            .attributes()
+            .for_each(|attribute| self.update(attribute));
+    }
+}
+
+/// Join SGR parameters into a single escape sequence,
+/// or an empty string if there's no parameter.
+fn sgr(params: Vec<String>) -> String {
+    match params.is_empty() {
+        true => String::new(),
+        false => format!("\x1b[{}m", params.join(";")),
+    }
+}
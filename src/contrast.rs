@@ -0,0 +1,174 @@
+//! Contrast between colors, following the WCAG 2.x definition,
+//! and helpers to pick a readable foreground on a given background.
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::{contrast::*, custom_color::*};
+//!
+//! let background = RGBColor::from(0xffd700);
+//! let foreground = background.readable_foreground();
+//! assert_eq!(foreground, RGBColor::from(0x000000));
+//! let badge = " NEW ".simple_fg(foreground).simple_bg(background);
+//! let black_on_gold = "\x1b[48;2;255;215;0m\x1b[38;2;0;0;0m NEW ";
+//! assert_eq!(badge, format!("{black_on_gold}\x1b[39m\x1b[49m"));
+//! assert!(foreground.contrast(background) >= ContrastLevel::Aaa.ratio());
+//! ```
+
+use crate::{
+    color_space::{to_linear, Oklch},
+    custom_color::RGBColor,
+};
+
+const BLACK: RGBColor = RGBColor { r: 0, g: 0, b: 0 };
+const WHITE: RGBColor = RGBColor {
+    r: 255,
+    g: 255,
+    b: 255,
+};
+
+/// Minimum contrast ratios required by the WCAG 2.x levels.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum ContrastLevel {
+    /// Level AA for large text, ratio 3:1.
+    AaLarge,
+
+    /// Level AA for normal text, ratio 4.5:1.
+    #[default]
+    Aa,
+
+    /// Level AAA for normal text, ratio 7:1.
+    Aaa,
+}
+
+impl ContrastLevel {
+    /// The minimum contrast ratio of the level.
+    pub fn ratio(self) -> f64 {
+        match self {
+            ContrastLevel::AaLarge => 3.0,
+            ContrastLevel::Aa => 4.5,
+            ContrastLevel::Aaa => 7.0,
+        }
+    }
+}
+
+impl RGBColor {
+    /// Relative luminance (`0.0..=1.0`) as defined by WCAG 2.x.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::from(0x000000).luminance(), 0.0);
+    /// assert_eq!(RGBColor::from(0xffffff).luminance(), 1.0);
+    /// ```
+    pub fn luminance(&self) -> f64 {
+        0.2126 * to_linear(self.r)
+            + 0.7152 * to_linear(self.g)
+            + 0.0722 * to_linear(self.b)
+    }
+
+    /// Contrast ratio (`1.0..=21.0`) between two colors,
+    /// the order of the colors doesn't matter.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// let black = RGBColor::from(0x000000);
+    /// let white = RGBColor::from(0xffffff);
+    /// assert_eq!(black.contrast(white), 21.0);
+    /// assert_eq!(white.contrast(black), 21.0);
+    /// assert_eq!(white.contrast(white), 1.0);
+    /// ```
+    pub fn contrast(&self, other: RGBColor) -> f64 {
+        let (a, b) = (self.luminance(), other.luminance());
+        (a.max(b) + 0.05) / (a.min(b) + 0.05)
+    }
+
+    /// Pick black or white, whichever has the higher contrast
+    /// when used as the foreground on current color.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// let black = RGBColor::from(0x000000);
+    /// let white = RGBColor::from(0xffffff);
+    /// assert_eq!(RGBColor::from(0xffff00).readable_foreground(), black);
+    /// assert_eq!(RGBColor::from(0x000080).readable_foreground(), white);
+    /// ```
+    pub fn readable_foreground(&self) -> RGBColor {
+        match BLACK.contrast(*self) >= WHITE.contrast(*self) {
+            true => BLACK,
+            false => WHITE,
+        }
+    }
+
+    /// Adjust current color as the foreground on the given background,
+    /// until their contrast reaches the given level.
+    ///
+    /// Current color is returned as is if it's already readable.
+    /// Otherwise it will be lightened or darkened in [Oklch] space,
+    /// whichever changes the lightness less.
+    /// If neither can reach the level,
+    /// the [readable_foreground](RGBColor::readable_foreground)
+    /// of the background is returned.
+    ///
+    /// ```rust
+    /// use terminal_font::{contrast::ContrastLevel, custom_color::RGBColor};
+    ///
+    /// let background = RGBColor::from(0x1e90ff);
+    /// let preferred = RGBColor::from(0x87cefa);
+    /// let level = ContrastLevel::Aa;
+    /// let adjusted = preferred.readable_on(background, level);
+    /// assert!(adjusted.contrast(background) >= level.ratio());
+    /// assert_ne!(adjusted, preferred);
+    ///
+    /// let black = RGBColor::from(0x000000);
+    /// assert_eq!(black.readable_on(background, level), black);
+    /// ```
+    pub fn readable_on(
+        self,
+        background: RGBColor,
+        level: ContrastLevel,
+    ) -> RGBColor {
+        let target = level.ratio();
+        if self.contrast(background) >= target {
+            return self;
+        }
+        let lightness = Oklch::from(self).l;
+        let lighter = search(|ratio| self.lighten(ratio), background, target);
+        let darker = search(|ratio| self.darken(ratio), background, target);
+        let change = |color: RGBColor| (Oklch::from(color).l - lightness).abs();
+        match (lighter, darker) {
+            (Some(lighter), Some(darker)) => {
+                match change(lighter) <= change(darker) {
+                    true => lighter,
+                    false => darker,
+                }
+            }
+            (Some(color), None) | (None, Some(color)) => color,
+            (None, None) => background.readable_foreground(),
+        }
+    }
+}
+
+/// Binary search the minimum ratio of the adjustment
+/// to reach the target contrast, if possible.
+fn search(
+    adjust: impl Fn(f64) -> RGBColor,
+    background: RGBColor,
+    target: f64,
+) -> Option<RGBColor> {
+    let reached = |ratio| adjust(ratio).contrast(background) >= target;
+    if !reached(1.0) {
+        return None;
+    }
+    let (mut low, mut high) = (0.0, 1.0);
+    for _ in 0..24 {
+        let middle = (low + high) / 2.0;
+        match reached(middle) {
+            true => high = middle,
+            false => low = middle,
+        }
+    }
+    Some(adjust(high))
+}
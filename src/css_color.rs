@@ -0,0 +1,388 @@
+//! Parse [RGBColor] from strings in CSS color syntax,
+//! usually used when reading colors from config files.
+//!
+//! Supported formats are:
+//!
+//! 1. Hex codes: `#1e90ff` and the short form `#abc`.
+//! 2. RGB functions: `rgb(30, 144, 255)`, `rgb(30 144 255)`
+//!    and percentages such as `rgb(100% 50% 0%)`.
+//! 3. HSL functions: `hsl(210, 100%, 56%)` and `hsl(210deg 100% 56%)`.
+//! 4. The 148 CSS [named colors](NAMED_COLORS), case insensitive.
+//!
+//! The alpha channel (`#rrggbbaa`, `rgba(...)`, `rgb(... / 0.5)`)
+//! is accepted but ignored, because terminals don't support transparency.
+//!
+//! ```rust
+//! use terminal_font::custom_color::RGBColor;
+//!
+//! let color: RGBColor = "#1e90ff".parse().unwrap();
+//! assert_eq!(color, RGBColor::from(0x1e90ff));
+//! assert_eq!("#abc".parse(), Ok(RGBColor::from(0xaabbcc)));
+//! assert_eq!("rgb(30, 144, 255)".parse(), Ok(color));
+//! assert_eq!("rgb(30 144 255 / 50%)".parse(), Ok(color));
+//! assert_eq!("hsl(210 100% 56%)".parse(), Ok(RGBColor::from(0x1f8fff)));
+//! assert_eq!("DodgerBlue".parse(), Ok(color));
+//! ```
+//!
+//! Errors are reported with [ParseColorError]:
+//!
+//! ```rust
+//! use terminal_font::{css_color::ParseColorError, custom_color::RGBColor};
+//!
+//! let error = "#12345".parse::<RGBColor>().unwrap_err();
+//! assert_eq!(error, ParseColorError::InvalidHex("#12345".to_string()));
+//! assert_eq!(error.to_string(), "invalid hex color: #12345");
+//!
+//! let error = "rgb(1, 2)".parse::<RGBColor>().unwrap_err();
+//! assert_eq!(error.to_string(), "invalid color function: rgb(1, 2)");
+//! ```
+
+use std::{error::Error, fmt, str::FromStr};
+
+use crate::{color_space::Hsl, custom_color::RGBColor};
+
+/// Error when parsing [RGBColor] from string.
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum ParseColorError {
+    /// The input string is empty or only contains whitespaces.
+    Empty,
+
+    /// Hex code with invalid digits or length.
+    InvalidHex(String),
+
+    /// Unknown color function, or wrong number of arguments.
+    InvalidFunction(String),
+
+    /// Invalid argument inside a color function.
+    InvalidComponent(String),
+
+    /// Not a hex code, nor a function, nor a known named color.
+    UnknownName(String),
+}
+
+impl fmt::Display for ParseColorError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            ParseColorError::Empty => write!(f, "empty color string"),
+            ParseColorError::InvalidHex(raw) => {
+                write!(f, "invalid hex color: {raw}")
+            }
+            ParseColorError::InvalidFunction(raw) => {
+                write!(f, "invalid color function: {raw}")
+            }
+            ParseColorError::InvalidComponent(raw) => {
+                write!(f, "invalid color component: {raw}")
+            }
+            ParseColorError::UnknownName(raw) => {
+                write!(f, "unknown color name: {raw}")
+            }
+        }
+    }
+}
+
+impl Error for ParseColorError {}
+
+impl FromStr for RGBColor {
+    type Err = ParseColorError;
+
+    /// Parse from CSS color syntax.
+    /// See the [css_color](crate::css_color) mod for supported formats.
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let raw = s.trim();
+        if raw.is_empty() {
+            return Err(ParseColorError::Empty);
+        }
+        if let Some(hex) = raw.strip_prefix('#') {
+            return parse_hex(hex)
+                .ok_or_else(|| ParseColorError::InvalidHex(raw.to_string()));
+        }
+        if let Some((name, args)) = raw
+            .strip_suffix(')')
+            .and_then(|function| function.split_once('('))
+        {
+            return parse_function(raw, name.trim(), args);
+        }
+        named_color(raw)
+            .ok_or_else(|| ParseColorError::UnknownName(raw.to_string()))
+    }
+}
+
+fn parse_hex(hex: &str) -> Option<RGBColor> {
+    if !hex.bytes().all(|byte| byte.is_ascii_hexdigit()) {
+        return None;
+    }
+    let digit = |index: usize| u8::from_str_radix(&hex[index..index + 1], 16);
+    let pair = |index: usize| u8::from_str_radix(&hex[index..index + 2], 16);
+    match hex.len() {
+        3 | 4 => Some(RGBColor::from((
+            digit(0).ok()? * 0x11,
+            digit(1).ok()? * 0x11,
+            digit(2).ok()? * 0x11,
+        ))),
+        6 | 8 => Some(RGBColor::from((
+            pair(0).ok()?,
+            pair(2).ok()?,
+            pair(4).ok()?,
+        ))),
+        _ => None,
+    }
+}
+
+fn parse_function(
+    raw: &str,
+    name: &str,
+    args: &str,
+) -> Result<RGBColor, ParseColorError> {
+    let invalid_function = || ParseColorError::InvalidFunction(raw.to_string());
+    let args: Vec<&str> = args
+        .split(|c: char| c == ',' || c == '/' || c.is_whitespace())
+        .filter(|arg| !arg.is_empty())
+        .collect();
+    if args.len() != 3 && args.len() != 4 {
+        return Err(invalid_function());
+    }
+    if let Some(alpha) = args.get(3) {
+        parse_number_or_percentage(alpha)?;
+    }
+
+    match name.to_ascii_lowercase().as_str() {
+        "rgb" | "rgba" => Ok(RGBColor::from((
+            parse_channel(args[0])?,
+            parse_channel(args[1])?,
+            parse_channel(args[2])?,
+        ))),
+        "hsl" | "hsla" => Ok(RGBColor::from(Hsl::new(
+            parse_hue(args[0])?,
+            parse_percentage(args[1])?,
+            parse_percentage(args[2])?,
+        ))),
+        _ => Err(invalid_function()),
+    }
+}
+
+fn invalid_component(raw: &str) -> ParseColorError {
+    ParseColorError::InvalidComponent(raw.to_string())
+}
+
+fn parse_number(raw: &str) -> Result<f64, ParseColorError> {
+    match raw.parse::<f64>() {
+        Ok(value) if value.is_finite() => Ok(value),
+        _ => Err(invalid_component(raw)),
+    }
+}
+
+/// Parse a number, or a percentage normalized into `0.0..=1.0`.
+/// Returns the value and whether it's a percentage.
+fn parse_number_or_percentage(
+    raw: &str,
+) -> Result<(f64, bool), ParseColorError> {
+    match raw.strip_suffix('%') {
+        Some(percentage) => Ok((parse_number(percentage)? / 100.0, true)),
+        None => Ok((parse_number(raw)?, false)),
+    }
+}
+
+/// Parse an RGB channel, either `0..=255` or a percentage.
+fn parse_channel(raw: &str) -> Result<u8, ParseColorError> {
+    let value = match parse_number_or_percentage(raw)? {
+        (value, true) => value * 255.0,
+        (value, false) => value,
+    };
+    Ok(value.clamp(0.0, 255.0).round() as u8)
+}
+
+/// Parse saturation or lightness, either a percentage or a number
+/// in `0..=100` (as in CSS Color Level 4), normalized into `0.0..=1.0`.
+fn parse_percentage(raw: &str) -> Result<f64, ParseColorError> {
+    match parse_number_or_percentage(raw)? {
+        (value, true) => Ok(value),
+        (value, false) => Ok(value / 100.0),
+    }
+}
+
+/// Parse hue in degrees, with optional `deg`, `rad`, `grad` or `turn` unit.
+fn parse_hue(raw: &str) -> Result<f64, ParseColorError> {
+    let units = [
+        ("deg", 1.0),
+        ("grad", 0.9),
+        ("rad", 180.0 / std::f64::consts::PI),
+        ("turn", 360.0),
+    ];
+    let lower = raw.to_ascii_lowercase();
+    for (unit, scale) in units {
+        if let Some(number) = lower.strip_suffix(unit) {
+            return parse_number(number)
+                .map(|value| value * scale)
+                .map_err(|_| invalid_component(raw));
+        }
+    }
+    parse_number(raw)
+}
+
+/// Find a CSS named color, case insensitive.
+///
+/// ```rust
+/// use terminal_font::{css_color::named_color, custom_color::RGBColor};
+/// assert_eq!(named_color("RebeccaPurple"), Some(RGBColor::from(0x663399)));
+/// assert_eq!(named_color("grey"), named_color("gray"));
+/// assert_eq!(named_color("unknown"), None);
+/// ```
+pub fn named_color(name: &str) -> Option<RGBColor> {
+    let name = name.to_ascii_lowercase();
+    NAMED_COLORS
+        .binary_search_by(|(key, _)| (*key).cmp(name.as_str()))
+        .ok()
+        .map(|index| RGBColor::from(NAMED_COLORS[index].1))
+}
+
+/// The 148 CSS named colors in alphabetical order.
+pub const NAMED_COLORS: [(&str, u32); 148] = [
+    ("aliceblue", 0xf0f8ff),
+    ("antiquewhite", 0xfaebd7),
+    ("aqua", 0x00ffff),
+    ("aquamarine", 0x7fffd4),
+    ("azure", 0xf0ffff),
+    ("beige", 0xf5f5dc),
+    ("bisque", 0xffe4c4),
+    ("black", 0x000000),
+    ("blanchedalmond", 0xffebcd),
+    ("blue", 0x0000ff),
+    ("blueviolet", 0x8a2be2),
+    ("brown", 0xa52a2a),
+    ("burlywood", 0xdeb887),
+    ("cadetblue", 0x5f9ea0),
+    ("chartreuse", 0x7fff00),
+    ("chocolate", 0xd2691e),
+    ("coral", 0xff7f50),
+    ("cornflowerblue", 0x6495ed),
+    ("cornsilk", 0xfff8dc),
+    ("crimson", 0xdc143c),
+    ("cyan", 0x00ffff),
+    ("darkblue", 0x00008b),
+    ("darkcyan", 0x008b8b),
+    ("darkgoldenrod", 0xb8860b),
+    ("darkgray", 0xa9a9a9),
+    ("darkgreen", 0x006400),
+    ("darkgrey", 0xa9a9a9),
+    ("darkkhaki", 0xbdb76b),
+    ("darkmagenta", 0x8b008b),
+    ("darkolivegreen", 0x556b2f),
+    ("darkorange", 0xff8c00),
+    ("darkorchid", 0x9932cc),
+    ("darkred", 0x8b0000),
+    ("darksalmon", 0xe9967a),
+    ("darkseagreen", 0x8fbc8f),
+    ("darkslateblue", 0x483d8b),
+    ("darkslategray", 0x2f4f4f),
+    ("darkslategrey", 0x2f4f4f),
+    ("darkturquoise", 0x00ced1),
+    ("darkviolet", 0x9400d3),
+    ("deeppink", 0xff1493),
+    ("deepskyblue", 0x00bfff),
+    ("dimgray", 0x696969),
+    ("dimgrey", 0x696969),
+    ("dodgerblue", 0x1e90ff),
+    ("firebrick", 0xb22222),
+    ("floralwhite", 0xfffaf0),
+    ("forestgreen", 0x228b22),
+    ("fuchsia", 0xff00ff),
+    ("gainsboro", 0xdcdcdc),
+    ("ghostwhite", 0xf8f8ff),
+    ("gold", 0xffd700),
+    ("goldenrod", 0xdaa520),
+    ("gray", 0x808080),
+    ("green", 0x008000),
+    ("greenyellow", 0xadff2f),
+    ("grey", 0x808080),
+    ("honeydew", 0xf0fff0),
+    ("hotpink", 0xff69b4),
+    ("indianred", 0xcd5c5c),
+    ("indigo", 0x4b0082),
+    ("ivory", 0xfffff0),
+    ("khaki", 0xf0e68c),
+    ("lavender", 0xe6e6fa),
+    ("lavenderblush", 0xfff0f5),
+    ("lawngreen", 0x7cfc00),
+    ("lemonchiffon", 0xfffacd),
+    ("lightblue", 0xadd8e6),
+    ("lightcoral", 0xf08080),
+    ("lightcyan", 0xe0ffff),
+    ("lightgoldenrodyellow", 0xfafad2),
+    ("lightgray", 0xd3d3d3),
+    ("lightgreen", 0x90ee90),
+    ("lightgrey", 0xd3d3d3),
+    ("lightpink", 0xffb6c1),
+    ("lightsalmon", 0xffa07a),
+    ("lightseagreen", 0x20b2aa),
+    ("lightskyblue", 0x87cefa),
+    ("lightslategray", 0x778899),
+    ("lightslategrey", 0x778899),
+    ("lightsteelblue", 0xb0c4de),
+    ("lightyellow", 0xffffe0),
+    ("lime", 0x00ff00),
+    ("limegreen", 0x32cd32),
+    ("linen", 0xfaf0e6),
+    ("magenta", 0xff00ff),
+    ("maroon", 0x800000),
+    ("mediumaquamarine", 0x66cdaa),
+    ("mediumblue", 0x0000cd),
+    ("mediumorchid", 0xba55d3),
+    ("mediumpurple", 0x9370db),
+    ("mediumseagreen", 0x3cb371),
+    ("mediumslateblue", 0x7b68ee),
+    ("mediumspringgreen", 0x00fa9a),
+    ("mediumturquoise", 0x48d1cc),
+    ("mediumvioletred", 0xc71585),
+    ("midnightblue", 0x191970),
+    ("mintcream", 0xf5fffa),
+    ("mistyrose", 0xffe4e1),
+    ("moccasin", 0xffe4b5),
+    ("navajowhite", 0xffdead),
+    ("navy", 0x000080),
+    ("oldlace", 0xfdf5e6),
+    ("olive", 0x808000),
+    ("olivedrab", 0x6b8e23),
+    ("orange", 0xffa500),
+    ("orangered", 0xff4500),
+    ("orchid", 0xda70d6),
+    ("palegoldenrod", 0xeee8aa),
+    ("palegreen", 0x98fb98),
+    ("paleturquoise", 0xafeeee),
+    ("palevioletred", 0xdb7093),
+    ("papayawhip", 0xffefd5),
+    ("peachpuff", 0xffdab9),
+    ("peru", 0xcd853f),
+    ("pink", 0xffc0cb),
+    ("plum", 0xdda0dd),
+    ("powderblue", 0xb0e0e6),
+    ("purple", 0x800080),
+    ("rebeccapurple", 0x663399),
+    ("red", 0xff0000),
+    ("rosybrown", 0xbc8f8f),
+    ("royalblue", 0x4169e1),
+    ("saddlebrown", 0x8b4513),
+    ("salmon", 0xfa8072),
+    ("sandybrown", 0xf4a460),
+    ("seagreen", 0x2e8b57),
+    ("seashell", 0xfff5ee),
+    ("sienna", 0xa0522d),
+    ("silver", 0xc0c0c0),
+    ("skyblue", 0x87ceeb),
+    ("slateblue", 0x6a5acd),
+    ("slategray", 0x708090),
+    ("slategrey", 0x708090),
+    ("snow", 0xfffafa),
+    ("springgreen", 0x00ff7f),
+    ("steelblue", 0x4682b4),
+    ("tan", 0xd2b48c),
+    ("teal", 0x008080),
+    ("thistle", 0xd8bfd8),
+    ("tomato", 0xff6347),
+    ("turquoise", 0x40e0d0),
+    ("violet", 0xee82ee),
+    ("wheat", 0xf5deb3),
+    ("white", 0xffffff),
+    ("whitesmoke", 0xf5f5f5),
+    ("yellow", 0xffff00),
+    ("yellowgreen", 0x9acd32),
+];
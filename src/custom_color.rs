@@ -1,4 +1,12 @@
-use crate::{cancel, custom::*, decorate::Decorate};
+use crate::{
+    cancel,
+    compose::Color,
+    custom::*,
+    decorate::Decorate,
+    detect::{color_depth, ColorDepth},
+    palette::{CUBE_LEVELS, SYSTEM},
+    render::Render,
+};
 
 /// It's strongly recommended to initialize a [RGBColor] object,
 /// and use it for further decorations, to make clear what color
@@ -6,7 +14,7 @@ use crate::{cancel, custom::*, decorate::Decorate};
 /// You can also initialize the color
 /// with [RGBColor::from] method from other formats,
 /// including rgb tuples and a single hex number.
-#[derive(Clone, Copy)]
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
 pub struct RGBColor {
     pub r: u8,
     pub g: u8,
@@ -54,6 +62,114 @@ impl From<u32> for RGBColor {
     }
 }
 
+impl RGBColor {
+    /// Squared euclidean distance between two colors in RGB space.
+    fn distance(&self, other: &RGBColor) -> u32 {
+        let d = |a: u8, b: u8| (a as i32 - b as i32).pow(2) as u32;
+        d(self.r, other.r) + d(self.g, other.g) + d(self.b, other.b)
+    }
+
+    /// Convert to the nearest 256 color code,
+    /// inside the 6x6x6 color cube (`16..=231`)
+    /// or the grayscale ramp (`232..=255`).
+    /// The 16 basic colors are skipped
+    /// because they are usually customized by the terminal themes.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    /// assert_eq!(RGBColor::from(0x000000).to_ansi256(), 16);
+    /// assert_eq!(RGBColor::from(0xff0000).to_ansi256(), 196);
+    /// assert_eq!(RGBColor::from(0x1e90ff).to_ansi256(), 33);
+    /// assert_eq!(RGBColor::from(0x808080).to_ansi256(), 244);
+    /// ```
+    pub fn to_ansi256(&self) -> u8 {
+        let level = |value: u8| {
+            (0..CUBE_LEVELS.len())
+                .min_by_key(|&i| (CUBE_LEVELS[i] as i32 - value as i32).abs())
+                .unwrap() as u8
+        };
+        let (r, g, b) = (level(self.r), level(self.g), level(self.b));
+        let cube = RGBColor::from((
+            CUBE_LEVELS[r as usize],
+            CUBE_LEVELS[g as usize],
+            CUBE_LEVELS[b as usize],
+        ));
+
+        let average = (self.r as u32 + self.g as u32 + self.b as u32) / 3;
+        let gray = (average.saturating_sub(3) / 10).min(23) as u8;
+        let gray_value = 8 + gray * 10;
+        let gray_color = RGBColor::from((gray_value, gray_value, gray_value));
+
+        match self.distance(&gray_color) < self.distance(&cube) {
+            true => 232 + gray,
+            false => 16 + 36 * r + 6 * g + b,
+        }
+    }
+
+    /// Convert to the nearest of the 16 basic colors,
+    /// returns the index in the order of their escape codes,
+    /// as in the [foreground](crate::foreground) and
+    /// [background](crate::background) mods.
+    /// The default xterm values ([SYSTEM]) are used as the reference colors.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    /// assert_eq!(RGBColor::from(0x000000).to_ansi16(), 0);
+    /// assert_eq!(RGBColor::from(0xc00000).to_ansi16(), 1);
+    /// assert_eq!(RGBColor::from(0xff2020).to_ansi16(), 9);
+    /// assert_eq!(RGBColor::from(0xfafafa).to_ansi16(), 15);
+    /// ```
+    pub fn to_ansi16(&self) -> u8 {
+        (0..SYSTEM.len())
+            .min_by_key(|&i| self.distance(&SYSTEM[i]))
+            .unwrap() as u8
+    }
+
+    /// Escape sequence to decorate foreground with current color,
+    /// encoded according to the target color depth:
+    /// RGB mode for true colors, the nearest code for 256 colors,
+    /// the nearest basic color for 16 colors,
+    /// and an empty string when colors are disabled.
+    ///
+    /// ```rust
+    /// use terminal_font::{custom_color::RGBColor, detect::ColorDepth};
+    ///
+    /// let color = RGBColor::from(0x1e90ff);
+    /// let truecolor = "\x1b[38;2;30;144;255m";
+    /// assert_eq!(color.foreground(ColorDepth::TrueColor), truecolor);
+    /// assert_eq!(color.foreground(ColorDepth::Ansi256), "\x1b[38;5;33m");
+    /// assert_eq!(color.foreground(ColorDepth::Ansi16), "\x1b[94m");
+    /// assert_eq!(color.foreground(ColorDepth::None), "");
+    /// ```
+    pub fn foreground(&self, depth: ColorDepth) -> String {
+        match depth {
+            ColorDepth::None => String::new(),
+            depth => Color::Rgb(*self).downsample(depth).foreground(),
+        }
+    }
+
+    /// Escape sequence to decorate background with current color,
+    /// encoded according to the target color depth.
+    /// See [RGBColor::foreground] for more details.
+    ///
+    /// ```rust
+    /// use terminal_font::{custom_color::RGBColor, detect::ColorDepth};
+    ///
+    /// let color = RGBColor::from(0x1e90ff);
+    /// let truecolor = "\x1b[48;2;30;144;255m";
+    /// assert_eq!(color.background(ColorDepth::TrueColor), truecolor);
+    /// assert_eq!(color.background(ColorDepth::Ansi256), "\x1b[48;5;33m");
+    /// assert_eq!(color.background(ColorDepth::Ansi16), "\x1b[104m");
+    /// assert_eq!(color.background(ColorDepth::None), "");
+    /// ```
+    pub fn background(&self, depth: ColorDepth) -> String {
+        match depth {
+            ColorDepth::None => String::new(),
+            depth => Color::Rgb(*self).downsample(depth).background(),
+        }
+    }
+}
+
 /// Encapsulation on the [Decorate] trait with
 /// custom color escape codes for style decorations,
 /// including RGB mode ([RGBColor]) and color code ([u8]) mode.
@@ -69,39 +185,122 @@ pub trait SimpleCustomColor<T>: Decorate {
 
 /// Color code ([u8]) version of custom color decoration.
 /// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
 /// use terminal_font::custom_color::*;
 /// assert_eq!(" hello ".simple_fg(123), "\x1b[38;5;123m hello \x1b[39m");
 /// assert_eq!(" hello ".simple_bg(123), "\x1b[48;5;123m hello \x1b[49m");
 /// ```
 impl<T: Decorate + AsRef<str>> SimpleCustomColor<u8> for T {
     fn simple_fg(&self, code: u8) -> String {
-        self.wrap(foreground_code(code), cancel::FOREGROUND)
+        self.wrap_escape(code_foreground(code), cancel::FOREGROUND)
     }
 
     fn simple_bg(&self, code: u8) -> String {
-        self.wrap(background_code(code), cancel::BACKGROUND)
+        self.wrap_escape(code_background(code), cancel::BACKGROUND)
+    }
+}
+
+/// Escape sequence of the color code as foreground,
+/// converted to a basic color when 256 colors are not supported.
+fn code_foreground(code: u8) -> String {
+    match color_depth() {
+        ColorDepth::Ansi256 | ColorDepth::TrueColor => foreground_code(code),
+        depth => Color::Code(code).downsample(depth).foreground(),
+    }
+}
+
+/// Escape sequence of the color code as background,
+/// converted to a basic color when 256 colors are not supported.
+fn code_background(code: u8) -> String {
+    match color_depth() {
+        ColorDepth::Ansi256 | ColorDepth::TrueColor => background_code(code),
+        depth => Color::Code(code).downsample(depth).background(),
     }
 }
 
 /// RGB ([RGBColor]) version of custom color decoration.
 /// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
 /// use terminal_font::custom_color::*;
 /// let c = RGBColor::from((143, 76, 78));
 /// assert_eq!(" hello ".simple_fg(c), "\x1b[38;2;143;76;78m hello \x1b[39m");
 /// assert_eq!(" hello ".simple_bg(c), "\x1b[48;2;143;76;78m hello \x1b[49m");
 /// ```
+///
+/// The color will be downsampled according to the global color depth
+/// (see the [detect](crate::detect) mod),
+/// so that it won't be rendered as garbage on terminals
+/// without true color support:
+///
+/// ```rust
+/// use terminal_font::{custom_color::*, detect::*};
+/// let c = RGBColor::from((208, 32, 32));
+/// set_color_depth(ColorDepth::Ansi256);
+/// assert_eq!(" hello ".simple_fg(c), "\x1b[38;5;160m hello \x1b[39m");
+/// set_color_depth(ColorDepth::Ansi16);
+/// assert_eq!(" hello ".simple_fg(c), "\x1b[31m hello \x1b[39m");
+/// ```
 impl<T: Decorate + AsRef<str>> SimpleCustomColor<RGBColor> for T {
     fn simple_fg(&self, color: RGBColor) -> String {
-        self.wrap(
-            foreground_rgb(color.r, color.g, color.b),
-            cancel::FOREGROUND,
-        )
+        self.wrap_escape(color.foreground(color_depth()), cancel::FOREGROUND)
     }
 
     fn simple_bg(&self, color: RGBColor) -> String {
-        self.wrap(
-            background_rgb(color.r, color.g, color.b),
-            cancel::BACKGROUND,
-        )
+        self.wrap_escape(color.background(color_depth()), cancel::BACKGROUND)
+    }
+}
+
+/// Encapsulation on the [Render] trait with
+/// custom color escape codes for style decorations,
+/// including RGB mode ([RGBColor]) and color code ([u8]) mode.
+///
+/// Those methods are the safe versions of [SimpleCustomColor],
+/// the outer color will be recovered after inner color cancels.
+/// See the [render](crate::render) mod for more details.
+pub trait RenderCustomColor<T>: Render {
+    fn fg(&self, code: T) -> String;
+    fn bg(&self, code: T) -> String;
+}
+
+/// Color code ([u8]) version of safe custom color decoration.
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::custom_color::*;
+/// assert_eq!(" hello ".fg(123), "\x1b[38;5;123m hello \x1b[39m");
+/// assert_eq!(" hello ".bg(123), "\x1b[48;5;123m hello \x1b[49m");
+///
+/// let raw = format!("a{}c", "b".simple_fg(1));
+/// let expected = "\x1b[38;5;2ma\x1b[38;5;1mb\x1b[39m\x1b[38;5;2mc\x1b[39m";
+/// assert_eq!(raw.fg(2), expected);
+/// ```
+impl<T: Render + AsRef<str>> RenderCustomColor<u8> for T {
+    fn fg(&self, code: u8) -> String {
+        self.render(code_foreground(code), cancel::FOREGROUND)
+    }
+
+    fn bg(&self, code: u8) -> String {
+        self.render(code_background(code), cancel::BACKGROUND)
+    }
+}
+
+/// RGB ([RGBColor]) version of safe custom color decoration.
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::custom_color::*;
+/// let c = RGBColor::from((143, 76, 78));
+/// assert_eq!(" hello ".fg(c), "\x1b[38;2;143;76;78m hello \x1b[39m");
+/// assert_eq!(" hello ".bg(c), "\x1b[48;2;143;76;78m hello \x1b[49m");
+/// ```
+impl<T: Render + AsRef<str>> RenderCustomColor<RGBColor> for T {
+    fn fg(&self, color: RGBColor) -> String {
+        self.render(color.foreground(color_depth()), cancel::FOREGROUND)
+    }
+
+    fn bg(&self, color: RGBColor) -> String {
+        self.render(color.background(color_depth()), cancel::BACKGROUND)
     }
 }
@@ -21,7 +21,10 @@
 //! without parsing the inner structure.
 //! This is guaranteed by the ansi escape rule.
 
-use crate::escape::{background, cancel, foreground, style::*};
+use crate::{
+    detect,
+    escape::{background, cancel, foreground, style::*},
+};
 
 #[allow(unused_imports)] // Docs only.
 use crate::{custom_color, render};
@@ -65,6 +68,34 @@ pub trait Decorate {
         prefix: T,
         suffix: U,
     ) -> String;
+
+    /// Wrap escape sequences as the prefix and the suffix,
+    /// or return the raw string when colors are disabled
+    /// according to the [detect] mod.
+    ///
+    /// All methods in the [SimpleStyle], [SimpleForeground]
+    /// and [SimpleBackground] supertraits are encapsulations of this method.
+    ///
+    /// ```rust
+    /// # use terminal_font::detect::{set_color_depth, ColorDepth};
+    /// # set_color_depth(ColorDepth::TrueColor);
+    /// use terminal_font::{decorate::Decorate, detect::*};
+    ///
+    /// let (prefix, suffix) = ("\x1b[1m", "\x1b[22m");
+    /// assert_eq!("hello".wrap_escape(prefix, suffix), "\x1b[1mhello\x1b[22m");
+    /// set_color_depth(ColorDepth::None);
+    /// assert_eq!("hello".wrap_escape(prefix, suffix), "hello");
+    /// ```
+    fn wrap_escape<T: AsRef<str>, U: AsRef<str>>(
+        &self,
+        prefix: T,
+        suffix: U,
+    ) -> String {
+        match detect::enabled() {
+            true => self.wrap(prefix, suffix),
+            false => self.wrap("", ""),
+        }
+    }
 }
 
 impl<T: AsRef<str>> Decorate for T {
@@ -86,6 +117,8 @@ impl<T: AsRef<str>> Decorate for T {
 /// and performance is more important.
 ///
 /// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
 /// use terminal_font::decorate::SimpleStyle;
 /// assert_eq!(" hello ".simple_bold(), "\x1b[1m hello \x1b[22m");
 /// assert_eq!(" hello ".simple_faint(), "\x1b[2m hello \x1b[22m");
@@ -102,67 +135,69 @@ pub trait SimpleStyle: Decorate {
     /// Also known as [SimpleStyleAlias::simple_heavy].
     /// See the documentation of the trait: [SimpleStyle].
     fn simple_bold(&self) -> String {
-        self.wrap(BOLD, cancel::BOLD_OR_FAINT)
+        self.wrap_escape(BOLD, cancel::BOLD_OR_FAINT)
     }
 
     /// Also known as [SimpleStyleAlias::simple_dim].
     /// See the documentation of the trait: [SimpleStyle].
     fn simple_faint(&self) -> String {
-        self.wrap(FAINT, cancel::BOLD_OR_FAINT)
+        self.wrap_escape(FAINT, cancel::BOLD_OR_FAINT)
     }
 
     /// Also known as [SimpleStyleAlias::oblique].
     /// See the documentation of the trait: [SimpleStyle].
     fn italic(&self) -> String {
-        self.wrap(ITALIC, cancel::ITALIC)
+        self.wrap_escape(ITALIC, cancel::ITALIC)
     }
 
     /// See the documentation of the trait: [SimpleStyle].
     fn simple_underline(&self) -> String {
-        self.wrap(UNDERLINE, cancel::UNDERLINE)
+        self.wrap_escape(UNDERLINE, cancel::UNDERLINE)
     }
 
     /// Usually unsupported by build-in terminals of common editors.
     /// See the documentation of the trait: [SimpleStyle].
     fn simple_blink(&self) -> String {
-        self.wrap(BLINK, cancel::BLINK)
+        self.wrap_escape(BLINK, cancel::BLINK)
     }
 
     /// Usually unsupported by common terminals.
     /// See the documentation of the trait: [SimpleStyle].
     fn simple_blink_fast(&self) -> String {
-        self.wrap(BLINK_FAST, cancel::BLINK)
+        self.wrap_escape(BLINK_FAST, cancel::BLINK)
     }
 
     /// Also known as [SimpleStyleAlias::negative].
     /// See the documentation of the trait: [SimpleStyle].
     fn inverse(&self) -> String {
-        self.wrap(INVERSE, cancel::INVERSE)
+        self.wrap_escape(INVERSE, cancel::INVERSE)
     }
 
     /// Also known as [SimpleStyleAlias::hidden].
     /// See the documentation of the trait: [SimpleStyle].
     fn conceal(&self) -> String {
-        self.wrap(CONCEAL, cancel::CONCEAL)
+        self.wrap_escape(CONCEAL, cancel::CONCEAL)
     }
 
     /// Also known as [SimpleStyleAlias::delete_line].
     /// See the documentation of the trait: [SimpleStyle].
     fn strikethrough(&self) -> String {
-        self.wrap(STRIKETHROUGH, cancel::STRIKETHROUGH)
+        self.wrap_escape(STRIKETHROUGH, cancel::STRIKETHROUGH)
     }
 
     /// Usually unsupported by common terminals,
     /// and sometimes displayed as a thick underline.
     /// See the documentation of the trait: [SimpleStyle].
     fn simple_double_underline(&self) -> String {
-        self.wrap(DOUBLE_UNDERLINE, cancel::UNDERLINE)
+        self.wrap_escape(DOUBLE_UNDERLINE, cancel::UNDERLINE)
     }
 }
 
 /// Aliases for some of the methods in the [SimpleStyle] trait.
 ///
 /// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
 /// use terminal_font::decorate::SimpleStyleAlias;
 /// assert_eq!(" hello ".simple_dim(), "\x1b[2m hello \x1b[22m");
 /// assert_eq!(" hello ".oblique(), "\x1b[3m hello \x1b[23m");
@@ -211,6 +246,8 @@ pub trait SimpleStyleAlias: SimpleStyle {
 /// and performance is more important.
 ///
 /// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
 /// use terminal_font::decorate::SimpleForeground;
 /// assert_eq!(" hello ".simple_black(), "\x1b[30m hello \x1b[39m");
 /// assert_eq!(" hello ".simple_red(), "\x1b[31m hello \x1b[39m");
@@ -231,67 +268,67 @@ pub trait SimpleStyleAlias: SimpleStyle {
 /// ```
 pub trait SimpleForeground: Decorate {
     fn simple_black(&self) -> String {
-        self.wrap(foreground::BLACK, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BLACK, cancel::FOREGROUND)
     }
 
     fn simple_red(&self) -> String {
-        self.wrap(foreground::RED, cancel::FOREGROUND)
+        self.wrap_escape(foreground::RED, cancel::FOREGROUND)
     }
 
     fn simple_green(&self) -> String {
-        self.wrap(foreground::GREEN, cancel::FOREGROUND)
+        self.wrap_escape(foreground::GREEN, cancel::FOREGROUND)
     }
 
     fn simple_yellow(&self) -> String {
-        self.wrap(foreground::YELLOW, cancel::FOREGROUND)
+        self.wrap_escape(foreground::YELLOW, cancel::FOREGROUND)
     }
 
     fn simple_blue(&self) -> String {
-        self.wrap(foreground::BLUE, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BLUE, cancel::FOREGROUND)
     }
 
     fn simple_magenta(&self) -> String {
-        self.wrap(foreground::MAGENTA, cancel::FOREGROUND)
+        self.wrap_escape(foreground::MAGENTA, cancel::FOREGROUND)
     }
 
     fn simple_cyan(&self) -> String {
-        self.wrap(foreground::CYAN, cancel::FOREGROUND)
+        self.wrap_escape(foreground::CYAN, cancel::FOREGROUND)
     }
 
     fn simple_white(&self) -> String {
-        self.wrap(foreground::WHITE, cancel::FOREGROUND)
+        self.wrap_escape(foreground::WHITE, cancel::FOREGROUND)
     }
 
     fn simple_hi_black(&self) -> String {
-        self.wrap(foreground::BRIGHT_BLACK, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BRIGHT_BLACK, cancel::FOREGROUND)
     }
 
     fn simple_hi_red(&self) -> String {
-        self.wrap(foreground::BRIGHT_RED, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BRIGHT_RED, cancel::FOREGROUND)
     }
 
     fn simple_hi_green(&self) -> String {
-        self.wrap(foreground::BRIGHT_GREEN, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BRIGHT_GREEN, cancel::FOREGROUND)
     }
 
     fn simple_hi_yellow(&self) -> String {
-        self.wrap(foreground::BRIGHT_YELLOW, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BRIGHT_YELLOW, cancel::FOREGROUND)
     }
 
     fn simple_hi_blue(&self) -> String {
-        self.wrap(foreground::BRIGHT_BLUE, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BRIGHT_BLUE, cancel::FOREGROUND)
     }
 
     fn simple_hi_magenta(&self) -> String {
-        self.wrap(foreground::BRIGHT_MAGENTA, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BRIGHT_MAGENTA, cancel::FOREGROUND)
     }
 
     fn simple_hi_cyan(&self) -> String {
-        self.wrap(foreground::BRIGHT_CYAN, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BRIGHT_CYAN, cancel::FOREGROUND)
     }
 
     fn simple_hi_white(&self) -> String {
-        self.wrap(foreground::BRIGHT_WHITE, cancel::FOREGROUND)
+        self.wrap_escape(foreground::BRIGHT_WHITE, cancel::FOREGROUND)
     }
 }
 
@@ -304,6 +341,8 @@ pub trait SimpleForeground: Decorate {
 /// and performance is more important.
 ///
 /// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
 /// use terminal_font::decorate::SimpleBackground;
 /// assert_eq!(" hello ".simple_bg_black(), "\x1b[40m hello \x1b[49m");
 /// assert_eq!(" hello ".simple_bg_red(), "\x1b[41m hello \x1b[49m");
@@ -324,67 +363,67 @@ pub trait SimpleForeground: Decorate {
 /// ```
 pub trait SimpleBackground: Decorate {
     fn simple_bg_black(&self) -> String {
-        self.wrap(background::BLACK, cancel::BACKGROUND)
+        self.wrap_escape(background::BLACK, cancel::BACKGROUND)
     }
 
     fn simple_bg_red(&self) -> String {
-        self.wrap(background::RED, cancel::BACKGROUND)
+        self.wrap_escape(background::RED, cancel::BACKGROUND)
     }
 
     fn simple_bg_green(&self) -> String {
-        self.wrap(background::GREEN, cancel::BACKGROUND)
+        self.wrap_escape(background::GREEN, cancel::BACKGROUND)
     }
 
     fn simple_bg_yellow(&self) -> String {
-        self.wrap(background::YELLOW, cancel::BACKGROUND)
+        self.wrap_escape(background::YELLOW, cancel::BACKGROUND)
     }
 
     fn simple_bg_blue(&self) -> String {
-        self.wrap(background::BLUE, cancel::BACKGROUND)
+        self.wrap_escape(background::BLUE, cancel::BACKGROUND)
     }
 
     fn simple_bg_magenta(&self) -> String {
-        self.wrap(background::MAGENTA, cancel::BACKGROUND)
+        self.wrap_escape(background::MAGENTA, cancel::BACKGROUND)
     }
 
     fn simple_bg_cyan(&self) -> String {
-        self.wrap(background::CYAN, cancel::BACKGROUND)
+        self.wrap_escape(background::CYAN, cancel::BACKGROUND)
     }
 
     fn simple_bg_white(&self) -> String {
-        self.wrap(background::WHITE, cancel::BACKGROUND)
+        self.wrap_escape(background::WHITE, cancel::BACKGROUND)
     }
 
     fn simple_bg_hi_black(&self) -> String {
-        self.wrap(background::BRIGHT_BLACK, cancel::BACKGROUND)
+        self.wrap_escape(background::BRIGHT_BLACK, cancel::BACKGROUND)
     }
 
     fn simple_bg_hi_red(&self) -> String {
-        self.wrap(background::BRIGHT_RED, cancel::BACKGROUND)
+        self.wrap_escape(background::BRIGHT_RED, cancel::BACKGROUND)
     }
 
     fn simple_bg_hi_green(&self) -> String {
-        self.wrap(background::BRIGHT_GREEN, cancel::BACKGROUND)
+        self.wrap_escape(background::BRIGHT_GREEN, cancel::BACKGROUND)
     }
 
     fn simple_bg_hi_yellow(&self) -> String {
-        self.wrap(background::BRIGHT_YELLOW, cancel::BACKGROUND)
+        self.wrap_escape(background::BRIGHT_YELLOW, cancel::BACKGROUND)
     }
 
     fn simple_bg_hi_blue(&self) -> String {
-        self.wrap(background::BRIGHT_BLUE, cancel::BACKGROUND)
+        self.wrap_escape(background::BRIGHT_BLUE, cancel::BACKGROUND)
     }
 
     fn simple_bg_hi_magenta(&self) -> String {
-        self.wrap(background::BRIGHT_MAGENTA, cancel::BACKGROUND)
+        self.wrap_escape(background::BRIGHT_MAGENTA, cancel::BACKGROUND)
     }
 
     fn simple_bg_hi_cyan(&self) -> String {
-        self.wrap(background::BRIGHT_CYAN, cancel::BACKGROUND)
+        self.wrap_escape(background::BRIGHT_CYAN, cancel::BACKGROUND)
     }
 
     fn simple_bg_hi_white(&self) -> String {
-        self.wrap(background::BRIGHT_WHITE, cancel::BACKGROUND)
+        self.wrap_escape(background::BRIGHT_WHITE, cancel::BACKGROUND)
     }
 }
 
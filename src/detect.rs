@@ -0,0 +1,280 @@
+//! Detect the color capability of the terminal.
+//!
+//! The decorations in this crate consult the global [ColorDepth],
+//! and return plain text when colors are disabled.
+//! The capability of the standard output is detected
+//! the first time the color depth is read (see [color_depth]),
+//! so escape sequences are not emitted into pipes or files,
+//! or when `NO_COLOR` is set.
+//! Call [set_color_depth] to override the detection,
+//! such as when the output is not the standard output.
+//!
+//! ```rust
+//! use terminal_font::{decorate::*, detect::*};
+//!
+//! set_color_depth(ColorDepth::None);
+//! assert_eq!("hello".simple_red(), "hello");
+//! set_color_depth(ColorDepth::TrueColor);
+//! assert_eq!("hello".simple_red(), "\x1b[31mhello\x1b[39m");
+//! ```
+//!
+//! ## Environment variables
+//!
+//! The detection follows those rules, in order:
+//!
+//! 1. `FORCE_COLOR`: `0` or `false` disables colors,
+//!    `1`, `2` and `3` force at least 16, 256 and true colors,
+//!    and other values (including empty) force at least 16 colors.
+//! 2. `NO_COLOR`: disables colors when it's present and not empty.
+//! 3. `CLICOLOR_FORCE`: forces at least 16 colors
+//!    when it's present, not empty and not `0`.
+//! 4. When not forced, colors are disabled if the stream is not a terminal,
+//!    or `CLICOLOR` is `0`, or `TERM` is `dumb`.
+//! 5. The depth is detected from `COLORTERM` (`truecolor` or `24bit`)
+//!    and `TERM` (such as `xterm-256color`).
+//!
+//! The detection can be tested by injecting a [FakeEnvironment]:
+//!
+//! ```rust
+//! use terminal_font::detect::*;
+//!
+//! let env = FakeEnvironment::new(true).with("TERM", "xterm-256color");
+//! assert_eq!(detect_with(&env), ColorDepth::Ansi256);
+//! let disabled = env.clone().with("NO_COLOR", "1");
+//! assert_eq!(detect_with(&disabled), ColorDepth::None);
+//!
+//! let pipe = FakeEnvironment::new(false).with("TERM", "xterm-256color");
+//! assert_eq!(detect_with(&pipe), ColorDepth::None);
+//! let forced = pipe.with("FORCE_COLOR", "3");
+//! assert_eq!(detect_with(&forced), ColorDepth::TrueColor);
+//! ```
+
+use std::{
+    io::IsTerminal,
+    sync::atomic::{AtomicU8, Ordering},
+};
+
+/// Color capability of a terminal, ordered from the lowest to the highest.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum ColorDepth {
+    /// No escape sequence should be emitted.
+    None,
+
+    /// Only the 16 basic colors in the
+    /// [foreground](crate::foreground) and [background](crate::background)
+    /// mods are supported.
+    Ansi16,
+
+    /// The 256 color codes are supported.
+    Ansi256,
+
+    /// The RGB colors are supported.
+    TrueColor,
+}
+
+impl ColorDepth {
+    const ALL: [ColorDepth; 4] = [
+        ColorDepth::None,
+        ColorDepth::Ansi16,
+        ColorDepth::Ansi256,
+        ColorDepth::TrueColor,
+    ];
+}
+
+/// The standard stream to detect.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub enum Stream {
+    Stdout,
+    Stderr,
+}
+
+/// Source of the information used by the detection,
+/// implement it to inject a fake environment.
+pub trait Environment {
+    /// Value of the environment variable, or [None] if not present.
+    fn var(&self, key: &str) -> Option<String>;
+
+    /// Whether the stream is connected to a terminal.
+    fn is_terminal(&self) -> bool;
+}
+
+/// The real environment of the current process.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct SystemEnvironment(pub Stream);
+
+impl Environment for SystemEnvironment {
+    fn var(&self, key: &str) -> Option<String> {
+        std::env::var(key).ok()
+    }
+
+    fn is_terminal(&self) -> bool {
+        match self.0 {
+            Stream::Stdout => std::io::stdout().is_terminal(),
+            Stream::Stderr => std::io::stderr().is_terminal(),
+        }
+    }
+}
+
+/// A fake environment with given variables, usually used for testing.
+#[derive(Clone, Debug, Default, PartialEq, Eq)]
+pub struct FakeEnvironment {
+    pub terminal: bool,
+    pub vars: Vec<(String, String)>,
+}
+
+impl FakeEnvironment {
+    pub fn new(terminal: bool) -> Self {
+        FakeEnvironment {
+            terminal,
+            vars: Vec::new(),
+        }
+    }
+
+    /// Set an environment variable, overriding the former one if exists.
+    pub fn with<K: AsRef<str>, V: AsRef<str>>(
+        mut self,
+        key: K,
+        value: V,
+    ) -> Self {
+        self.vars.retain(|(k, _)| k != key.as_ref());
+        self.vars
+            .push((key.as_ref().to_string(), value.as_ref().to_string()));
+        self
+    }
+}
+
+impl Environment for FakeEnvironment {
+    fn var(&self, key: &str) -> Option<String> {
+        self.vars
+            .iter()
+            .find(|(k, _)| k == key)
+            .map(|(_, value)| value.clone())
+    }
+
+    fn is_terminal(&self) -> bool {
+        self.terminal
+    }
+}
+
+/// Detect the color capability of the given standard stream
+/// from the real environment of the current process.
+/// See the [detect](crate::detect) mod for the detection rules.
+pub fn detect(stream: Stream) -> ColorDepth {
+    detect_with(&SystemEnvironment(stream))
+}
+
+/// Detect the color capability from the given environment.
+/// See the [detect](crate::detect) mod for the detection rules.
+///
+/// ```rust
+/// use terminal_font::detect::*;
+///
+/// let env = FakeEnvironment::new(true);
+/// let xterm = env.clone().with("TERM", "xterm");
+/// assert_eq!(detect_with(&xterm), ColorDepth::Ansi16);
+/// let truecolor = xterm.clone().with("COLORTERM", "truecolor");
+/// assert_eq!(detect_with(&truecolor), ColorDepth::TrueColor);
+/// assert_eq!(detect_with(&xterm.with("CLICOLOR", "0")), ColorDepth::None);
+/// assert_eq!(detect_with(&env.with("TERM", "dumb")), ColorDepth::None);
+///
+/// let pipe = FakeEnvironment::new(false).with("CLICOLOR_FORCE", "1");
+/// assert_eq!(detect_with(&pipe), ColorDepth::Ansi16);
+/// assert_eq!(detect_with(&pipe.with("FORCE_COLOR", "0")), ColorDepth::None);
+/// ```
+pub fn detect_with<E: Environment>(env: &E) -> ColorDepth {
+    let present = |key: &str| env.var(key).filter(|value| !value.is_empty());
+
+    let forced = match env.var("FORCE_COLOR").as_deref() {
+        Some("0" | "false") => return ColorDepth::None,
+        Some("2") => Some(ColorDepth::Ansi256),
+        Some("3") => Some(ColorDepth::TrueColor),
+        Some(_) => Some(ColorDepth::Ansi16),
+        None => None,
+    };
+    if forced.is_none() && present("NO_COLOR").is_some() {
+        return ColorDepth::None;
+    }
+    let forced = forced.or(match present("CLICOLOR_FORCE").as_deref() {
+        None | Some("0") => None,
+        Some(_) => Some(ColorDepth::Ansi16),
+    });
+
+    let term = env.var("TERM").unwrap_or_default();
+    if forced.is_none()
+        && (!env.is_terminal()
+            || env.var("CLICOLOR").as_deref() == Some("0")
+            || term == "dumb")
+    {
+        return ColorDepth::None;
+    }
+
+    let colorterm = env.var("COLORTERM").unwrap_or_default();
+    let detected = if colorterm == "truecolor"
+        || colorterm == "24bit"
+        || term.ends_with("-direct")
+        || term.contains("truecolor")
+        || term.contains("24bit")
+    {
+        ColorDepth::TrueColor
+    } else if term.contains("256color") {
+        ColorDepth::Ansi256
+    } else if !term.is_empty() && term != "dumb" {
+        ColorDepth::Ansi16
+    } else if cfg!(windows) && env.var("WT_SESSION").is_some() {
+        ColorDepth::TrueColor
+    } else if cfg!(windows) {
+        ColorDepth::Ansi16
+    } else {
+        ColorDepth::None
+    };
+    detected.max(forced.unwrap_or(ColorDepth::None))
+}
+
+/// Sentinel of the global color depth before it's detected or overridden.
+const UNDETECTED: u8 = u8::MAX;
+
+/// Global color depth, detected on the first read.
+static COLOR_DEPTH: AtomicU8 = AtomicU8::new(UNDETECTED);
+
+/// The global color depth consulted by the decorations.
+/// The standard output is detected on the first read
+/// unless it's already overridden by [set_color_depth].
+pub fn color_depth() -> ColorDepth {
+    let mut depth = COLOR_DEPTH.load(Ordering::Relaxed);
+    if depth == UNDETECTED {
+        let detected = detect(Stream::Stdout) as u8;
+        // Keep the override if set_color_depth is called meanwhile.
+        depth = match COLOR_DEPTH.compare_exchange(
+            UNDETECTED,
+            detected,
+            Ordering::Relaxed,
+            Ordering::Relaxed,
+        ) {
+            Ok(_) => detected,
+            Err(current) => current,
+        };
+    }
+    ColorDepth::ALL[depth as usize]
+}
+
+/// Override the global color depth consulted by the decorations,
+/// the detection will not run afterwards.
+pub fn set_color_depth(depth: ColorDepth) {
+    COLOR_DEPTH.store(depth as u8, Ordering::Relaxed);
+}
+
+/// Whether the decorations should emit escape sequences.
+pub fn enabled() -> bool {
+    color_depth() != ColorDepth::None
+}
+
+/// Detect the capability of the standard output again,
+/// and apply it as the global color depth.
+/// Returns the detected color depth.
+/// It's only necessary when the environment has changed,
+/// as the detection runs on the first read of [color_depth].
+pub fn init() -> ColorDepth {
+    let depth = detect(Stream::Stdout);
+    set_color_depth(depth);
+    depth
+}
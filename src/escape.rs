@@ -7,6 +7,14 @@
 //! It's strongly not recommended to use this mod directly.
 //! For common utilities, you may refer to encapsulations
 //! inside the [decorate] and [render] mod.
+//!
+//! This mod also provides the [tokenize] function,
+//! which splits a string into plain text and escape sequences.
+//! It's the inverse of the [escape!] macro,
+//! and the basis of processing decorated strings,
+//! such as [strip_ansi].
+
+use std::borrow::Cow;
 
 #[allow(unused_imports)] // Docs only.
 use crate::{custom_color, decorate, escape, render};
@@ -79,6 +87,145 @@ macro_rules! escape {
     }};
 }
 
+/// Generate a static decorated string in compile time,
+/// with the combined escape sequence as prefix and the cancels as suffix.
+/// The names are the same as the methods of the [decorate::SimpleStyle],
+/// [decorate::SimpleForeground] and [decorate::SimpleBackground] traits:
+///
+/// ```rust
+/// use terminal_font::styled;
+///
+/// const FAIL: &str = styled!(bold, red; "FAIL");
+/// assert_eq!(FAIL, "\x1b[1;31mFAIL\x1b[22;39m");
+/// assert_eq!(styled!(bg_hi_blue; "x"), "\x1b[104mx\x1b[49m");
+/// ```
+///
+/// Unknown names are compile errors:
+///
+/// ```rust,compile_fail
+/// use terminal_font::styled;
+///
+/// let _ = styled!(bold, purple; "FAIL");
+/// ```
+///
+/// As the result is generated in compile time,
+/// it won't consult the [detect](crate::detect) mod,
+/// and the escape sequences are always kept.
+#[macro_export]
+macro_rules! styled {
+    ($($name:ident),+ ; $text:literal) => {
+        concat!(
+            "\x1b[", $crate::styled!(@codes $($name),+), "m",
+            $text,
+            "\x1b[", $crate::styled!(@cancels $($name),+), "m",
+        )
+    };
+    (@codes $first:ident $(, $rest:ident)*) => {
+        concat!(
+            $crate::styled!(@code $first),
+            $(";", $crate::styled!(@code $rest),)*
+        )
+    };
+    (@cancels $first:ident $(, $rest:ident)*) => {
+        concat!(
+            $crate::styled!(@cancel $first),
+            $(";", $crate::styled!(@cancel $rest),)*
+        )
+    };
+    (@code bold) => {"1"};
+    (@code heavy) => {"1"};
+    (@code faint) => {"2"};
+    (@code dim) => {"2"};
+    (@code italic) => {"3"};
+    (@code underline) => {"4"};
+    (@code blink) => {"5"};
+    (@code blink_fast) => {"6"};
+    (@code inverse) => {"7"};
+    (@code conceal) => {"8"};
+    (@code strikethrough) => {"9"};
+    (@code double_underline) => {"21"};
+    (@code black) => {"30"};
+    (@code red) => {"31"};
+    (@code green) => {"32"};
+    (@code yellow) => {"33"};
+    (@code blue) => {"34"};
+    (@code magenta) => {"35"};
+    (@code cyan) => {"36"};
+    (@code white) => {"37"};
+    (@code hi_black) => {"90"};
+    (@code hi_red) => {"91"};
+    (@code hi_green) => {"92"};
+    (@code hi_yellow) => {"93"};
+    (@code hi_blue) => {"94"};
+    (@code hi_magenta) => {"95"};
+    (@code hi_cyan) => {"96"};
+    (@code hi_white) => {"97"};
+    (@code bg_black) => {"40"};
+    (@code bg_red) => {"41"};
+    (@code bg_green) => {"42"};
+    (@code bg_yellow) => {"43"};
+    (@code bg_blue) => {"44"};
+    (@code bg_magenta) => {"45"};
+    (@code bg_cyan) => {"46"};
+    (@code bg_white) => {"47"};
+    (@code bg_hi_black) => {"100"};
+    (@code bg_hi_red) => {"101"};
+    (@code bg_hi_green) => {"102"};
+    (@code bg_hi_yellow) => {"103"};
+    (@code bg_hi_blue) => {"104"};
+    (@code bg_hi_magenta) => {"105"};
+    (@code bg_hi_cyan) => {"106"};
+    (@code bg_hi_white) => {"107"};
+    (@code $name:ident) => {
+        compile_error!(concat!("unknown style name: ", stringify!($name)))
+    };
+    (@cancel bold) => {"22"};
+    (@cancel heavy) => {"22"};
+    (@cancel faint) => {"22"};
+    (@cancel dim) => {"22"};
+    (@cancel italic) => {"23"};
+    (@cancel underline) => {"24"};
+    (@cancel blink) => {"25"};
+    (@cancel blink_fast) => {"25"};
+    (@cancel inverse) => {"27"};
+    (@cancel conceal) => {"28"};
+    (@cancel strikethrough) => {"29"};
+    (@cancel double_underline) => {"24"};
+    (@cancel black) => {"39"};
+    (@cancel red) => {"39"};
+    (@cancel green) => {"39"};
+    (@cancel yellow) => {"39"};
+    (@cancel blue) => {"39"};
+    (@cancel magenta) => {"39"};
+    (@cancel cyan) => {"39"};
+    (@cancel white) => {"39"};
+    (@cancel hi_black) => {"39"};
+    (@cancel hi_red) => {"39"};
+    (@cancel hi_green) => {"39"};
+    (@cancel hi_yellow) => {"39"};
+    (@cancel hi_blue) => {"39"};
+    (@cancel hi_magenta) => {"39"};
+    (@cancel hi_cyan) => {"39"};
+    (@cancel hi_white) => {"39"};
+    (@cancel bg_black) => {"49"};
+    (@cancel bg_red) => {"49"};
+    (@cancel bg_green) => {"49"};
+    (@cancel bg_yellow) => {"49"};
+    (@cancel bg_blue) => {"49"};
+    (@cancel bg_magenta) => {"49"};
+    (@cancel bg_cyan) => {"49"};
+    (@cancel bg_white) => {"49"};
+    (@cancel bg_hi_black) => {"49"};
+    (@cancel bg_hi_red) => {"49"};
+    (@cancel bg_hi_green) => {"49"};
+    (@cancel bg_hi_yellow) => {"49"};
+    (@cancel bg_hi_blue) => {"49"};
+    (@cancel bg_hi_magenta) => {"49"};
+    (@cancel bg_hi_cyan) => {"49"};
+    (@cancel bg_hi_white) => {"49"};
+    (@cancel $name:ident) => {""};
+}
+
 /// Cancel decoration escape codes, usually used as suffix.
 pub mod cancel {
     pub const ALL: &str = escape!(0);
@@ -155,6 +302,7 @@ pub mod background {
 pub mod custom {
     pub const FOREGROUND: u8 = 38;
     pub const BACKGROUND: u8 = 48;
+    pub const UNDERLINE_COLOR: u8 = 58;
     pub const CODE_MODE: u8 = 5;
     pub const RGB_MODE: u8 = 2;
 
@@ -178,3 +326,411 @@ pub mod custom {
         escape!(BACKGROUND, RGB_MODE, r, g, b)
     }
 }
+
+/// A piece of string split by [tokenize],
+/// either plain text or a single escape sequence.
+/// Each variant keeps its `raw` content,
+/// so that the original string can be rebuilt by joining [Token::raw].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Token<'a> {
+    /// Plain text that contains no escape character.
+    Text(&'a str),
+
+    /// Select Graphic Rendition (`ESC [ params m`),
+    /// such as the ones generated by the [escape!] macro.
+    Sgr { raw: &'a str, params: SgrParams<'a> },
+
+    /// Other Control Sequence Introducer sequences
+    /// (`ESC [ params intermediates action`), such as cursor movements.
+    Csi {
+        raw: &'a str,
+        params: &'a str,
+        intermediates: &'a str,
+        action: char,
+    },
+
+    /// Operating System Command (`ESC ] data BEL` or `ESC ] data ESC \`),
+    /// such as window titles and hyperlinks.
+    Osc { raw: &'a str, data: &'a str },
+
+    /// Device Control String (`ESC P data ESC \`).
+    /// The other string sequences (SOS `ESC X`, PM `ESC ^`
+    /// and APC `ESC _`) are also treated as this variant.
+    Dcs { raw: &'a str, data: &'a str },
+
+    /// Other escape sequences (such as `ESC c` and `ESC ( B`),
+    /// or a lone `ESC` that cannot be parsed as a complete sequence.
+    Esc { raw: &'a str },
+}
+
+impl<'a> Token<'a> {
+    /// The original content of the token inside the parsed string.
+    pub fn raw(&self) -> &'a str {
+        match self {
+            Token::Text(raw)
+            | Token::Sgr { raw, .. }
+            | Token::Csi { raw, .. }
+            | Token::Osc { raw, .. }
+            | Token::Dcs { raw, .. }
+            | Token::Esc { raw } => raw,
+        }
+    }
+
+    /// Whether the token is an escape sequence rather than plain text.
+    pub fn is_escape(&self) -> bool {
+        !matches!(self, Token::Text(_))
+    }
+}
+
+/// Split the string into [Token]s of plain text and escape sequences.
+/// It's the inverse of the [escape!] macro.
+///
+/// ```rust
+/// use terminal_font::{escape, escape::*};
+///
+/// let raw = format!("{}hello{}", escape!(1, 31), cancel::ALL);
+/// let tokens: Vec<Token> = tokenize(&raw).collect();
+/// assert_eq!(tokens.len(), 3);
+/// assert_eq!(tokens[1], Token::Text("hello"));
+/// match tokens[0] {
+///     Token::Sgr { raw, params } => {
+///         assert_eq!(raw, "\x1b[1;31m");
+///         assert_eq!(params.iter().collect::<Vec<_>>(), vec![1, 31]);
+///     }
+///     _ => unreachable!(),
+/// }
+///
+/// // Joining the raw contents will get the original string.
+/// let joined: String = tokenize(&raw).map(|token| token.raw()).collect();
+/// assert_eq!(joined, raw);
+/// ```
+///
+/// Escape sequences other than SGR are also recognized:
+///
+/// ```rust
+/// use terminal_font::escape::*;
+///
+/// let raw = "\x1b[2K\x1b]0;title\x07\x1bPdata\x1b\\\x1bc\x1b";
+/// let tokens: Vec<Token> = tokenize(raw).collect();
+/// assert_eq!(tokens, vec![
+///     Token::Csi {
+///         raw: "\x1b[2K",
+///         params: "2",
+///         intermediates: "",
+///         action: 'K',
+///     },
+///     Token::Osc { raw: "\x1b]0;title\x07", data: "0;title" },
+///     Token::Dcs { raw: "\x1bPdata\x1b\\", data: "data" },
+///     Token::Esc { raw: "\x1bc" },
+///     Token::Esc { raw: "\x1b" },
+/// ]);
+/// ```
+pub fn tokenize(raw: &str) -> Tokens<'_> {
+    Tokens { rest: raw }
+}
+
+/// Iterator over the [Token]s of a string, created by [tokenize].
+#[derive(Clone, Debug)]
+pub struct Tokens<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Tokens<'a> {
+    type Item = Token<'a>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.rest.is_empty() {
+            return None;
+        }
+        let (token, length) = match self.rest.find('\x1b') {
+            Some(0) => parse_escape(self.rest),
+            Some(index) => (Token::Text(&self.rest[..index]), index),
+            None => (Token::Text(self.rest), self.rest.len()),
+        };
+        self.rest = &self.rest[length..];
+        Some(token)
+    }
+}
+
+/// Parse the escape sequence at the beginning of the string,
+/// returns the token and its length in bytes.
+fn parse_escape(raw: &str) -> (Token<'_>, usize) {
+    let bytes = raw.as_bytes();
+    let lone = (Token::Esc { raw: &raw[..1] }, 1);
+    match bytes.get(1) {
+        Some(b'[') => {
+            let params_end = skip_while(bytes, 2, 0x30..=0x3f);
+            let intermediates_end = skip_while(bytes, params_end, 0x20..=0x2f);
+            match bytes.get(intermediates_end) {
+                Some(action @ 0x40..=0x7e) => {
+                    let end = intermediates_end + 1;
+                    let raw = &raw[..end];
+                    let params = &raw[2..params_end];
+                    let intermediates = &raw[params_end..intermediates_end];
+                    let token = if *action == b'm' && intermediates.is_empty() {
+                        Token::Sgr {
+                            raw,
+                            params: SgrParams(params),
+                        }
+                    } else {
+                        Token::Csi {
+                            raw,
+                            params,
+                            intermediates,
+                            action: *action as char,
+                        }
+                    };
+                    (token, end)
+                }
+                _ => lone,
+            }
+        }
+        Some(b']') => {
+            let terminator = raw[2..]
+                .find(['\x07', '\x1b'])
+                .map(|index| index + 2)
+                .and_then(|index| match bytes[index] {
+                    b'\x07' => Some((index, index + 1)),
+                    _ if bytes.get(index + 1) == Some(&b'\\') => {
+                        Some((index, index + 2))
+                    }
+                    _ => None,
+                });
+            match terminator {
+                Some((data_end, end)) => {
+                    let token = Token::Osc {
+                        raw: &raw[..end],
+                        data: &raw[2..data_end],
+                    };
+                    (token, end)
+                }
+                None => lone,
+            }
+        }
+        Some(b'P' | b'X' | b'^' | b'_') => match raw[2..].find("\x1b\\") {
+            Some(index) => {
+                let end = index + 4;
+                let token = Token::Dcs {
+                    raw: &raw[..end],
+                    data: &raw[2..index + 2],
+                };
+                (token, end)
+            }
+            None => lone,
+        },
+        _ => {
+            let intermediates_end = skip_while(bytes, 1, 0x20..=0x2f);
+            match bytes.get(intermediates_end) {
+                Some(0x30..=0x7e) => {
+                    let end = intermediates_end + 1;
+                    (Token::Esc { raw: &raw[..end] }, end)
+                }
+                _ => lone,
+            }
+        }
+    }
+}
+
+fn skip_while(
+    bytes: &[u8],
+    start: usize,
+    range: std::ops::RangeInclusive<u8>,
+) -> usize {
+    let mut index = start;
+    while index < bytes.len() && range.contains(&bytes[index]) {
+        index += 1;
+    }
+    index
+}
+
+/// Remove all escape sequences from the string, keeping the plain text,
+/// including those from [escape!], [decorate] and foreign ones
+/// such as cursor movements (CSI) and window titles (OSC).
+/// The input is borrowed as is when it contains no escape at all.
+///
+/// ```rust
+/// use std::borrow::Cow;
+/// use terminal_font::{decorate::*, escape::*};
+///
+/// let raw = format!("{}\x1b[2K\x1b]0;title\x07!", "hi".simple_red());
+/// assert_eq!(strip_ansi(&raw), "hi!");
+/// assert!(matches!(strip_ansi("plain"), Cow::Borrowed("plain")));
+/// ```
+pub fn strip_ansi(raw: &str) -> Cow<'_, str> {
+    if !raw.contains('\x1b') {
+        return Cow::Borrowed(raw);
+    }
+    Cow::Owned(
+        tokenize(raw)
+            .filter_map(|token| match token {
+                Token::Text(text) => Some(text),
+                _ => None,
+            })
+            .collect(),
+    )
+}
+
+/// Parameter list of an SGR escape sequence,
+/// the content between `ESC [` and `m`.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct SgrParams<'a>(&'a str);
+
+impl<'a> SgrParams<'a> {
+    /// The raw parameter string, such as `1;31`.
+    pub fn as_str(&self) -> &'a str {
+        self.0
+    }
+
+    /// Iterate the numeric parameters separated by `;`.
+    /// Empty parameter is treated as `0` according to the ANSI standard,
+    /// and invalid parameters (including colon separated ones) are skipped.
+    ///
+    /// ```rust
+    /// use terminal_font::escape::*;
+    ///
+    /// let params: Vec<u16> = match tokenize("\x1b[;1;38;5;2m").next() {
+    ///     Some(Token::Sgr { params, .. }) => params.iter().collect(),
+    ///     _ => unreachable!(),
+    /// };
+    /// assert_eq!(params, vec![0, 1, 38, 5, 2]);
+    /// ```
+    pub fn iter(&self) -> impl Iterator<Item = u16> + 'a {
+        self.0.split(';').filter_map(|param| match param {
+            "" => Some(0),
+            param => param.parse().ok(),
+        })
+    }
+
+    /// Iterate the parameters grouped as [SgrAttribute]s,
+    /// so that the arguments of extended colors
+    /// (`38;5;n`, `38;2;r;g;b` and the colon separated forms)
+    /// won't be treated as single codes.
+    ///
+    /// ```rust
+    /// use terminal_font::escape::*;
+    ///
+    /// let raw = "\x1b[1;38;5;22;48:2::1:2:3;39m";
+    /// let attributes: Vec<SgrAttribute> = match tokenize(raw).next() {
+    ///     Some(Token::Sgr { params, .. }) => params.attributes().collect(),
+    ///     _ => unreachable!(),
+    /// };
+    /// assert_eq!(attributes, vec![
+    ///     SgrAttribute::Code(1),
+    ///     SgrAttribute::Foreground(ExtendedColor::Code(22)),
+    ///     SgrAttribute::Background(ExtendedColor::Rgb((1, 2, 3).into())),
+    ///     SgrAttribute::Code(39),
+    /// ]);
+    /// ```
+    pub fn attributes(&self) -> SgrAttributes<'a> {
+        SgrAttributes {
+            params: self.0.split(';'),
+        }
+    }
+}
+
+/// A single attribute inside an SGR escape sequence.
+/// See [SgrParams::attributes].
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum SgrAttribute {
+    /// Single code attributes, such as `1` (bold) and `31` (red foreground).
+    Code(u16),
+
+    /// Extended foreground color (`38`).
+    Foreground(ExtendedColor),
+
+    /// Extended background color (`48`).
+    Background(ExtendedColor),
+
+    /// Extended underline color (`58`), seldom supported.
+    Underline(ExtendedColor),
+}
+
+/// Extended color inside an SGR escape sequence,
+/// either a 256 color code or an RGB color.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum ExtendedColor {
+    Code(u8),
+    Rgb(crate::custom_color::RGBColor),
+}
+
+/// Iterator over the [SgrAttribute]s, created by [SgrParams::attributes].
+#[derive(Clone, Debug)]
+pub struct SgrAttributes<'a> {
+    params: std::str::Split<'a, char>,
+}
+
+impl SgrAttributes<'_> {
+    fn next_u8(&mut self) -> Option<u8> {
+        self.params.next().and_then(|param| param.parse().ok())
+    }
+}
+
+impl Iterator for SgrAttributes<'_> {
+    type Item = SgrAttribute;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        loop {
+            let param = self.params.next()?;
+            if param.contains(':') {
+                if let Some(attribute) = parse_colon_attribute(param) {
+                    return Some(attribute);
+                }
+                continue;
+            }
+            let code = match param {
+                "" => 0,
+                param => match param.parse::<u16>() {
+                    Ok(code) => code,
+                    Err(_) => continue,
+                },
+            };
+            let wrapper = match u8::try_from(code) {
+                Ok(custom::FOREGROUND) => SgrAttribute::Foreground,
+                Ok(custom::BACKGROUND) => SgrAttribute::Background,
+                Ok(custom::UNDERLINE_COLOR) => SgrAttribute::Underline,
+                _ => return Some(SgrAttribute::Code(code)),
+            };
+            let color = match self.next_u8() {
+                Some(custom::CODE_MODE) => {
+                    self.next_u8().map(ExtendedColor::Code)
+                }
+                Some(custom::RGB_MODE) => {
+                    match (self.next_u8(), self.next_u8(), self.next_u8()) {
+                        (Some(r), Some(g), Some(b)) => {
+                            Some(ExtendedColor::Rgb((r, g, b).into()))
+                        }
+                        _ => None,
+                    }
+                }
+                _ => None,
+            };
+            if let Some(color) = color {
+                return Some(wrapper(color));
+            }
+        }
+    }
+}
+
+/// Parse colon separated extended colors,
+/// such as `38:5:n`, `38:2:r:g:b` and `38:2:colorspace:r:g:b`.
+fn parse_colon_attribute(param: &str) -> Option<SgrAttribute> {
+    let parts: Vec<&str> = param.split(':').collect();
+    let wrapper = match parts[0].parse::<u8>().ok()? {
+        custom::FOREGROUND => SgrAttribute::Foreground,
+        custom::BACKGROUND => SgrAttribute::Background,
+        custom::UNDERLINE_COLOR => SgrAttribute::Underline,
+        _ => return None,
+    };
+    let number = |index: usize| parts.get(index)?.parse::<u8>().ok();
+    let color = match (parts.get(1)?.parse::<u8>().ok()?, parts.len()) {
+        (custom::CODE_MODE, 3) => ExtendedColor::Code(number(2)?),
+        (custom::RGB_MODE, 5) => {
+            ExtendedColor::Rgb((number(2)?, number(3)?, number(4)?).into())
+        }
+        (custom::RGB_MODE, 6) => {
+            ExtendedColor::Rgb((number(3)?, number(4)?, number(5)?).into())
+        }
+        _ => return None,
+    };
+    Some(wrapper(color))
+}
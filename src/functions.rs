@@ -276,3 +276,233 @@ pub mod simple_background {
         raw.simple_bg_hi_white()
     }
 }
+
+pub mod render_style {
+    #[allow(unused_imports)] // Docs only.
+    use crate::{functions::render_style_alias::*, render};
+
+    use crate::render::*;
+
+    /// Also known as [heavy].
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bold<T: AsRef<str>>(raw: T) -> String {
+        raw.bold()
+    }
+
+    /// Also known as [dim].
+    /// See [render] about the difference from the `simple` ones.
+    pub fn faint<T: AsRef<str>>(raw: T) -> String {
+        raw.faint()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn underline<T: AsRef<str>>(raw: T) -> String {
+        raw.underline()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn blink<T: AsRef<str>>(raw: T) -> String {
+        raw.blink()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn blink_fast<T: AsRef<str>>(raw: T) -> String {
+        raw.blink_fast()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn double_underline<T: AsRef<str>>(raw: T) -> String {
+        raw.double_underline()
+    }
+}
+
+pub mod render_style_alias {
+    #[allow(unused_imports)] // Docs only.
+    use crate::{functions::render_style::*, render};
+
+    use crate::render::*;
+
+    /// Alias of [bold].
+    /// See [render] about the difference from the `simple` ones.
+    pub fn heavy<T: AsRef<str>>(raw: T) -> String {
+        raw.heavy()
+    }
+
+    /// Alias of [faint].
+    /// See [render] about the difference from the `simple` ones.
+    pub fn dim<T: AsRef<str>>(raw: T) -> String {
+        raw.dim()
+    }
+}
+
+pub mod render_foreground {
+    #[allow(unused_imports)] // Docs only.
+    use crate::render;
+    use crate::render::*;
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn black<T: AsRef<str>>(raw: T) -> String {
+        raw.black()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn red<T: AsRef<str>>(raw: T) -> String {
+        raw.red()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn green<T: AsRef<str>>(raw: T) -> String {
+        raw.green()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn yellow<T: AsRef<str>>(raw: T) -> String {
+        raw.yellow()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn blue<T: AsRef<str>>(raw: T) -> String {
+        raw.blue()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn magenta<T: AsRef<str>>(raw: T) -> String {
+        raw.magenta()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn cyan<T: AsRef<str>>(raw: T) -> String {
+        raw.cyan()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn white<T: AsRef<str>>(raw: T) -> String {
+        raw.white()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn hi_black<T: AsRef<str>>(raw: T) -> String {
+        raw.hi_black()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn hi_red<T: AsRef<str>>(raw: T) -> String {
+        raw.hi_red()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn hi_green<T: AsRef<str>>(raw: T) -> String {
+        raw.hi_green()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn hi_yellow<T: AsRef<str>>(raw: T) -> String {
+        raw.hi_yellow()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn hi_blue<T: AsRef<str>>(raw: T) -> String {
+        raw.hi_blue()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn hi_magenta<T: AsRef<str>>(raw: T) -> String {
+        raw.hi_magenta()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn hi_cyan<T: AsRef<str>>(raw: T) -> String {
+        raw.hi_cyan()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn hi_white<T: AsRef<str>>(raw: T) -> String {
+        raw.hi_white()
+    }
+}
+
+pub mod render_background {
+    #[allow(unused_imports)] // Docs only.
+    use crate::render;
+    use crate::render::*;
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_black<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_black()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_red<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_red()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_green<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_green()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_yellow<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_yellow()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_blue<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_blue()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_magenta<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_magenta()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_cyan<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_cyan()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_white<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_white()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_hi_black<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_hi_black()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_hi_red<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_hi_red()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_hi_green<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_hi_green()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_hi_yellow<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_hi_yellow()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_hi_blue<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_hi_blue()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_hi_magenta<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_hi_magenta()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_hi_cyan<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_hi_cyan()
+    }
+
+    /// See [render] about the difference from the `simple` ones.
+    pub fn bg_hi_white<T: AsRef<str>>(raw: T) -> String {
+        raw.bg_hi_white()
+    }
+}
@@ -0,0 +1,202 @@
+//! Color gradients across text.
+//!
+//! A [Gradient] interpolates between two or more [RGBColor] stops,
+//! and applies one color to each grapheme cluster
+//! (see [graphemes]) of the text,
+//! from the first stop at the beginning to the last stop at the end.
+//! Escape sequences already inside the text are kept as is,
+//! and don't take any position of the gradient.
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::{color_adjust::Interpolation, gradient::*};
+//! use terminal_font::custom_color::RGBColor;
+//!
+//! let red = RGBColor::from(0xff0000);
+//! let blue = RGBColor::from(0x0000ff);
+//! let gradient = Gradient::new([red, blue])
+//!     .interpolation(Interpolation::Srgb);
+//! assert_eq!(
+//!     gradient.apply("abc"),
+//!     "\x1b[38;2;255;0;0ma\x1b[38;2;128;0;128mb\x1b[38;2;0;0;255mc\x1b[39m",
+//! );
+//! ```
+
+use crate::{
+    color_adjust::Interpolation,
+    custom_color::RGBColor,
+    detect::{self, ColorDepth},
+    escape::{cancel, tokenize, Token},
+    unicode::graphemes,
+};
+
+/// Interpolated colors between stops, applied across text.
+#[derive(Clone, Debug, Default, PartialEq)]
+pub struct Gradient {
+    pub stops: Vec<RGBColor>,
+    pub interpolation: Interpolation,
+    pub background: bool,
+}
+
+impl Gradient {
+    /// Create a foreground gradient from the given stops,
+    /// interpolated in the default [Interpolation] space.
+    /// The stops are evenly distributed across the text.
+    pub fn new<I: IntoIterator<Item = RGBColor>>(stops: I) -> Self {
+        Gradient {
+            stops: stops.into_iter().collect(),
+            ..Default::default()
+        }
+    }
+
+    /// Set the color space to interpolate between stops.
+    pub fn interpolation(mut self, interpolation: Interpolation) -> Self {
+        self.interpolation = interpolation;
+        self
+    }
+
+    /// Apply the gradient to the background rather than the foreground.
+    pub fn background(mut self) -> Self {
+        self.background = true;
+        self
+    }
+
+    /// The color at the given position (clamped into `0.0..=1.0`),
+    /// or [None] if there's no stop.
+    ///
+    /// ```rust
+    /// use terminal_font::{custom_color::RGBColor, gradient::Gradient};
+    ///
+    /// let stops = [0xff0000, 0x00ff00, 0x0000ff].map(RGBColor::from);
+    /// let gradient = Gradient::new(stops);
+    /// assert_eq!(gradient.color_at(0.0), Some(stops[0]));
+    /// assert_eq!(gradient.color_at(0.5), Some(stops[1]));
+    /// assert_eq!(gradient.color_at(2.0), Some(stops[2]));
+    /// assert_eq!(Gradient::new([]).color_at(0.5), None);
+    /// ```
+    pub fn color_at(&self, position: f64) -> Option<RGBColor> {
+        let segments = self.stops.len().checked_sub(1)?;
+        if segments == 0 {
+            return Some(self.stops[0]);
+        }
+        let scaled = position.clamp(0.0, 1.0) * segments as f64;
+        let index = (scaled.floor() as usize).min(segments - 1);
+        let (from, to) = (self.stops[index], self.stops[index + 1]);
+        Some(from.mix(to, scaled - index as f64, self.interpolation))
+    }
+
+    /// Evenly sample the given count of colors from the gradient,
+    /// including both ends.
+    ///
+    /// ```rust
+    /// use terminal_font::{color_adjust::Interpolation, gradient::Gradient};
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// let stops = [0x000000, 0xffffff].map(RGBColor::from);
+    /// let gradient = Gradient::new(stops).interpolation(Interpolation::Srgb);
+    /// let colors = [0x000000, 0x808080, 0xffffff].map(RGBColor::from);
+    /// assert_eq!(gradient.colors(3), colors);
+    /// ```
+    pub fn colors(&self, count: usize) -> Vec<RGBColor> {
+        (0..count)
+            .filter_map(|index| self.color_at(position(index, count)))
+            .collect()
+    }
+
+    /// Apply the gradient across the grapheme clusters of the text.
+    /// The colors are downsampled according to the global
+    /// [color_depth](detect::color_depth),
+    /// and the raw text is returned when colors are disabled.
+    ///
+    /// ```rust
+    /// # use terminal_font::detect::{set_color_depth, ColorDepth};
+    /// # set_color_depth(ColorDepth::TrueColor);
+    /// use terminal_font::{custom_color::RGBColor, gradient::Gradient};
+    ///
+    /// let stops = [0xff0000, 0x0000ff].map(RGBColor::from);
+    /// let gradient = Gradient::new(stops).background();
+    /// let red = "\x1b[48;2;255;0;0m";
+    /// let blue = "\x1b[48;2;0;0;255m";
+    /// assert_eq!(gradient.apply("e\u{301}b"), format!(
+    ///     "{red}e\u{301}{blue}b\x1b[49m",
+    /// ));
+    /// ```
+    pub fn apply<T: AsRef<str>>(&self, raw: T) -> String {
+        let raw = raw.as_ref();
+        let depth = detect::color_depth();
+        if depth == ColorDepth::None || self.stops.is_empty() {
+            return raw.to_string();
+        }
+
+        let count: usize = tokenize(raw)
+            .map(|token| match token {
+                Token::Text(text) => graphemes(text).count(),
+                _ => 0,
+            })
+            .sum();
+        let mut result = String::with_capacity(raw.len() + count * 20);
+        let mut index = 0;
+        let mut last = String::new();
+        let mut emitted = false;
+        for token in tokenize(raw) {
+            let Token::Text(text) = token else {
+                // The inner escape may override the color.
+                result.push_str(token.raw());
+                last.clear();
+                continue;
+            };
+            for cluster in graphemes(text) {
+                let color = self.color_at(position(index, count)).unwrap();
+                index += 1;
+                let escape = match self.background {
+                    true => color.background(depth),
+                    false => color.foreground(depth),
+                };
+                if escape != last {
+                    result.push_str(&escape);
+                    last = escape;
+                    emitted = true;
+                }
+                result.push_str(cluster);
+            }
+        }
+        if emitted {
+            result.push_str(match self.background {
+                true => cancel::BACKGROUND,
+                false => cancel::FOREGROUND,
+            });
+        }
+        result
+    }
+}
+
+/// Position of the index among count items, including both ends.
+fn position(index: usize, count: usize) -> f64 {
+    match count {
+        0 | 1 => 0.0,
+        _ => index as f64 / (count - 1) as f64,
+    }
+}
+
+/// Apply a foreground [Gradient] with the given stops
+/// in the default [Interpolation] space.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{custom_color::RGBColor, gradient::gradient};
+///
+/// let white = RGBColor::from(0xffffff);
+/// let text = gradient("hi", &[white, white]);
+/// assert_eq!(text, "\x1b[38;2;255;255;255mhi\x1b[39m");
+/// ```
+pub fn gradient<T: AsRef<str>>(raw: T, stops: &[RGBColor]) -> String {
+    Gradient::new(stops.iter().copied()).apply(raw)
+}
+
+/// Apply a background [Gradient] with the given stops
+/// in the default [Interpolation] space.
+pub fn bg_gradient<T: AsRef<str>>(raw: T, stops: &[RGBColor]) -> String {
+    Gradient::new(stops.iter().copied()).background().apply(raw)
+}
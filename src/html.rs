@@ -0,0 +1,316 @@
+//! Export decorated strings to HTML.
+//!
+//! SGR escape sequences (from this crate or from other programs)
+//! are converted into `<span>` elements, either with inline styles
+//! or with CSS classes, and other escape sequences are removed.
+//! Line breaks are kept as is, so wrap the result in a `<pre>` element.
+//!
+//! ```rust
+//! use terminal_font::html::to_html;
+//!
+//! let raw = "\x1b[1;31mfail\x1b[0m: 1 < 2";
+//! assert_eq!(
+//!     to_html(raw),
+//!     "<span style=\"color:#cd0000;font-weight:bold\">fail</span>: 1 &lt; 2",
+//! );
+//! ```
+
+use std::fmt::Write;
+
+use crate::{
+    compose::{Attributes, Color, Style},
+    custom_color::RGBColor,
+    escape::{tokenize, Token},
+    palette,
+};
+
+/// Options of the HTML export.
+///
+/// With CSS classes, the basic colors and the attributes
+/// are exported as classes (see [Html::stylesheet]),
+/// while the 256 color codes beyond the basic ones, the RGB colors
+/// and the text decorations (underline, strikethrough and blink,
+/// which share the same CSS property) are still exported as inline styles.
+///
+/// ```rust
+/// use terminal_font::{custom_color::RGBColor, html::*};
+///
+/// let html = Html::new().classes("tf-");
+/// let raw = "\x1b[3;32mok\x1b[38;5;208m!\x1b[0m";
+/// assert_eq!(
+///     html.export(raw),
+///     "<span class=\"tf-fg-2 tf-italic\">ok</span>\
+///     <span class=\"tf-italic\" style=\"color:#ff8700\">!</span>",
+/// );
+/// assert!(html.stylesheet().contains(".tf-fg-2 { color: #00cd00; }"));
+///
+/// let mut palette = terminal_font::palette::SYSTEM;
+/// palette[2] = RGBColor::from(0x2ea043);
+/// let html = Html::new().palette(palette);
+/// let green = "<span style=\"color:#2ea043\">ok</span>";
+/// assert_eq!(html.export("\x1b[32mok\x1b[39m"), green);
+///
+/// let html = Html::new().classes("tf-");
+/// let lines = "text-decoration:underline line-through";
+/// let lines = format!("<span style=\"{lines}\">x</span>");
+/// assert_eq!(html.export("\x1b[4;9mx"), lines);
+/// let double = "<span style=\"text-decoration:underline double\">x</span>";
+/// assert_eq!(to_html("\x1b[4;21mx"), double);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Html {
+    /// Values of the 16 basic colors, in the order of their escape codes.
+    /// Xterm default values ([palette::SYSTEM]) by default.
+    pub palette: [RGBColor; 16],
+
+    /// Default foreground, used when inverting text without a background.
+    pub foreground: RGBColor,
+
+    /// Default background, used when inverting text without a foreground.
+    pub background: RGBColor,
+
+    /// Prefix of the CSS class names, or [None] for inline styles.
+    pub class_prefix: Option<String>,
+}
+
+impl Default for Html {
+    fn default() -> Self {
+        Html {
+            palette: palette::SYSTEM,
+            foreground: palette::SYSTEM[7],
+            background: palette::SYSTEM[0],
+            class_prefix: None,
+        }
+    }
+}
+
+impl Html {
+    /// Export with inline styles and the xterm palette.
+    pub fn new() -> Self {
+        Html::default()
+    }
+
+    pub fn palette(mut self, palette: [RGBColor; 16]) -> Self {
+        self.palette = palette;
+        self
+    }
+
+    /// Set the default foreground and background colors.
+    pub fn defaults(
+        mut self,
+        foreground: RGBColor,
+        background: RGBColor,
+    ) -> Self {
+        self.foreground = foreground;
+        self.background = background;
+        self
+    }
+
+    /// Export with CSS classes of the given name prefix.
+    /// Only ASCII letters, digits, `-` and `_` are kept in the prefix,
+    /// so that it's always a valid part of a CSS identifier.
+    ///
+    /// ```rust
+    /// use terminal_font::html::Html;
+    ///
+    /// let html = Html::new().classes("a\"><script>");
+    /// assert_eq!(html.class_prefix.as_deref(), Some("ascript"));
+    /// ```
+    pub fn classes<T: Into<String>>(mut self, prefix: T) -> Self {
+        let mut prefix = prefix.into();
+        prefix.retain(|c| c.is_ascii_alphanumeric() || c == '-' || c == '_');
+        self.class_prefix = Some(prefix);
+        self
+    }
+
+    /// The RGB value of the color according to the palette.
+    pub fn rgb(&self, color: Color) -> RGBColor {
+        match palette_index(color) {
+            Some(index) => self.palette[index as usize],
+            None => color.to_rgb(),
+        }
+    }
+
+    /// Convert the decorated string into HTML,
+    /// special characters of HTML are escaped.
+    pub fn export<T: AsRef<str>>(&self, raw: T) -> String {
+        let mut runs: Vec<(Style, String)> = Vec::new();
+        let mut style = Style::new();
+        for token in tokenize(raw.as_ref()) {
+            match token {
+                Token::Text(text) => match runs.last_mut() {
+                    Some((last, run)) if *last == style => run.push_str(text),
+                    _ => runs.push((style, text.to_string())),
+                },
+                Token::Sgr { params, .. } => style.update_sgr(params),
+                _ => {}
+            }
+        }
+
+        let mut result = String::new();
+        for (style, text) in runs {
+            let text = escape_html(&text);
+            if style.is_plain() {
+                result.push_str(&text);
+                continue;
+            }
+            let (classes, inline) = self.css(style);
+            result.push_str("<span");
+            if !classes.is_empty() {
+                write!(result, " class=\"{}\"", classes.join(" ")).unwrap();
+            }
+            if !inline.is_empty() {
+                write!(result, " style=\"{}\"", inline.join(";")).unwrap();
+            }
+            write!(result, ">{text}</span>").unwrap();
+        }
+        result
+    }
+
+    /// CSS rules of the classes used in class mode,
+    /// or an empty string in inline mode.
+    pub fn stylesheet(&self) -> String {
+        let Some(prefix) = &self.class_prefix else {
+            return String::new();
+        };
+        let mut css = String::new();
+        for (index, color) in self.palette.iter().enumerate() {
+            let hex = hex(*color);
+            writeln!(css, ".{prefix}fg-{index} {{ color: {hex}; }}").unwrap();
+            writeln!(css, ".{prefix}bg-{index} {{ background-color: {hex}; }}")
+                .unwrap();
+        }
+        for (attribute, _, _) in Attributes::TABLE {
+            if let Some((name, rule)) = attribute_css(attribute) {
+                let rule = rule.replace(':', ": ");
+                writeln!(css, ".{prefix}{name} {{ {rule}; }}").unwrap();
+            }
+        }
+        css
+    }
+
+    /// Class names and inline declarations of the style.
+    fn css(&self, style: Style) -> (Vec<String>, Vec<String>) {
+        let (mut foreground, mut background) =
+            (style.foreground, style.background);
+        if style.attributes.contains(Attributes::INVERSE) {
+            (foreground, background) = (
+                Some(background.unwrap_or(Color::Rgb(self.background))),
+                Some(foreground.unwrap_or(Color::Rgb(self.foreground))),
+            );
+        }
+
+        let (mut classes, mut inline) = (Vec::new(), Vec::new());
+        let colors = [
+            ("fg", "color", foreground),
+            ("bg", "background-color", background),
+        ];
+        for (short, property, color) in colors {
+            let Some(color) = color else { continue };
+            match (&self.class_prefix, palette_index(color)) {
+                (Some(prefix), Some(index)) => {
+                    classes.push(format!("{prefix}{short}-{index}"))
+                }
+                _ => {
+                    inline.push(format!("{property}:{}", hex(self.rgb(color))))
+                }
+            }
+        }
+
+        for (attribute, _, _) in Attributes::TABLE {
+            if !style.attributes.contains(attribute) {
+                continue;
+            }
+            let Some((name, rule)) = attribute_css(attribute) else {
+                continue;
+            };
+            match &self.class_prefix {
+                Some(prefix) => classes.push(format!("{prefix}{name}")),
+                None => inline.push(rule.to_string()),
+            }
+        }
+        inline.extend(text_decoration(style.attributes));
+        (classes, inline)
+    }
+}
+
+/// Index of the color inside the 16 color palette,
+/// or [None] for the extended colors beyond it.
+fn palette_index(color: Color) -> Option<u8> {
+    match color {
+        Color::Code(code @ 0..=15) => Some(code),
+        color => color.basic_index(),
+    }
+}
+
+/// Class name and CSS declaration of an attribute,
+/// or [None] for those exported otherwise:
+/// inverse as swapped colors, and the lines by [text_decoration].
+fn attribute_css(
+    attribute: Attributes,
+) -> Option<(&'static str, &'static str)> {
+    match attribute {
+        Attributes::BOLD => Some(("bold", "font-weight:bold")),
+        Attributes::FAINT => Some(("faint", "opacity:0.5")),
+        Attributes::ITALIC => Some(("italic", "font-style:italic")),
+        Attributes::CONCEAL => Some(("conceal", "visibility:hidden")),
+        _ => None,
+    }
+}
+
+/// The combined `text-decoration` declaration of all the lines,
+/// as separated declarations would override each other.
+fn text_decoration(attributes: Attributes) -> Option<String> {
+    let double = attributes.contains(Attributes::DOUBLE_UNDERLINE);
+    let lines = [
+        (
+            double || attributes.contains(Attributes::UNDERLINE),
+            "underline",
+        ),
+        (
+            attributes.contains(Attributes::STRIKETHROUGH),
+            "line-through",
+        ),
+        (
+            attributes.contains(Attributes::BLINK)
+                || attributes.contains(Attributes::BLINK_FAST),
+            "blink",
+        ),
+        (double, "double"),
+    ];
+    let values: Vec<&str> = lines
+        .into_iter()
+        .filter(|(enabled, _)| *enabled)
+        .map(|(_, value)| value)
+        .collect();
+    match values.is_empty() {
+        true => None,
+        false => Some(format!("text-decoration:{}", values.join(" "))),
+    }
+}
+
+fn hex(color: RGBColor) -> String {
+    format!("#{:02x}{:02x}{:02x}", color.r, color.g, color.b)
+}
+
+/// Escape the special characters of HTML.
+fn escape_html(raw: &str) -> String {
+    let mut result = String::with_capacity(raw.len());
+    for c in raw.chars() {
+        match c {
+            '&' => result.push_str("&amp;"),
+            '<' => result.push_str("&lt;"),
+            '>' => result.push_str("&gt;"),
+            '"' => result.push_str("&quot;"),
+            '\'' => result.push_str("&#39;"),
+            c => result.push(c),
+        }
+    }
+    result
+}
+
+/// Convert the decorated string into HTML with inline styles,
+/// see [Html] for more options.
+pub fn to_html<T: AsRef<str>>(raw: T) -> String {
+    Html::new().export(raw)
+}
@@ -0,0 +1,465 @@
+//! Allocation-free lazy decorations.
+//!
+//! Every method in the [SimpleStyle], [SimpleForeground], [SimpleBackground]
+//! and [SimpleCustomColor] traits goes through [Decorate::wrap],
+//! which formats the result into a new [String].
+//! When the result is only used for printing, such allocation is wasted.
+//!
+//! Methods in this mod have the same name as the ones in the traits above
+//! but with a `lazy_` prefix. They return a [Lazy] wrapper
+//! that holds a reference of the raw string,
+//! and writes the prefix, the raw string and the suffix directly
+//! into the formatter when displayed.
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::{decorate::*, lazy::*};
+//!
+//! let name = "hello";
+//! assert_eq!(format!("{}", name.lazy_simple_red()), name.simple_red());
+//! assert_eq!(name.lazy_simple_red().to_string(), "\x1b[31mhello\x1b[39m");
+//! ```
+//!
+//! Just like the `simple` methods, those wrappers won't parse the raw string.
+//! See [decorate](crate::decorate) about what does `simple` name prefix means.
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    compose::Color,
+    custom::{BACKGROUND, FOREGROUND},
+    custom_color::RGBColor,
+    detect,
+    escape::{background, cancel, foreground, style::*, ExtendedColor},
+    text::write_aligned,
+};
+
+#[allow(unused_imports)] // Docs only.
+use crate::{custom_color::SimpleCustomColor, decorate::*};
+
+/// Lazy decoration that writes the prefix, the raw string and the suffix
+/// into the formatter when displayed, without any heap allocation.
+/// Only the raw string will be written when colors are disabled
+/// according to the [detect] mod.
+/// The width, fill and alignment of the formatter are honored
+/// according to the display width of the raw string:
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::lazy::*;
+///
+/// let ok = format!("{:>4}", "ok".lazy_simple_green());
+/// assert_eq!(ok, "  \x1b[32mok\x1b[39m");
+/// ```
+///
+/// See the [lazy](crate::lazy) mod for more details.
+#[derive(Clone, Copy, Debug)]
+pub struct Lazy<'a, T: ?Sized, P = &'static str> {
+    raw: &'a T,
+    prefix: P,
+    suffix: &'static str,
+}
+
+impl<'a, T: ?Sized, P> Lazy<'a, T, P> {
+    pub fn new(raw: &'a T, prefix: P, suffix: &'static str) -> Self {
+        Lazy {
+            raw,
+            prefix,
+            suffix,
+        }
+    }
+}
+
+impl<T: AsRef<str> + ?Sized, P: Display> Display for Lazy<'_, T, P> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let raw = self.raw.as_ref();
+        write_aligned(f, raw, |f| match detect::enabled() {
+            true => write!(f, "{}{}{}", self.prefix, raw, self.suffix),
+            false => f.write_str(raw),
+        })
+    }
+}
+
+/// Prefix of custom colors, written directly into the formatter
+/// rather than formatted into a [String] first.
+/// RGB colors will be downsampled according to the global color depth.
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub struct CustomPrefix {
+    target: u8,
+    color: ExtendedColor,
+}
+
+impl CustomPrefix {
+    pub fn foreground(color: ExtendedColor) -> Self {
+        CustomPrefix {
+            target: FOREGROUND,
+            color,
+        }
+    }
+
+    pub fn background(color: ExtendedColor) -> Self {
+        CustomPrefix {
+            target: BACKGROUND,
+            color,
+        }
+    }
+}
+
+impl Display for CustomPrefix {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let color = match self.color {
+            ExtendedColor::Code(code) => Color::Code(code),
+            ExtendedColor::Rgb(color) => Color::Rgb(color),
+        };
+        f.write_str("\x1b[")?;
+        color
+            .downsample(detect::color_depth())
+            .write_params(f, self.target == BACKGROUND)?;
+        f.write_str("m")
+    }
+}
+
+/// Lazy versions of the methods in the [SimpleStyle] trait.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::lazy::LazySimpleStyle;
+/// assert_eq!("x".lazy_simple_bold().to_string(), "\x1b[1mx\x1b[22m");
+/// assert_eq!("x".lazy_simple_faint().to_string(), "\x1b[2mx\x1b[22m");
+/// assert_eq!("x".lazy_italic().to_string(), "\x1b[3mx\x1b[23m");
+/// assert_eq!("x".lazy_simple_underline().to_string(), "\x1b[4mx\x1b[24m");
+/// assert_eq!("x".lazy_simple_blink().to_string(), "\x1b[5mx\x1b[25m");
+/// assert_eq!("x".lazy_simple_blink_fast().to_string(), "\x1b[6mx\x1b[25m");
+/// assert_eq!("x".lazy_inverse().to_string(), "\x1b[7mx\x1b[27m");
+/// assert_eq!("x".lazy_conceal().to_string(), "\x1b[8mx\x1b[28m");
+/// assert_eq!("x".lazy_strikethrough().to_string(), "\x1b[9mx\x1b[29m");
+/// let double_underline = "x".lazy_simple_double_underline();
+/// assert_eq!(double_underline.to_string(), "\x1b[21mx\x1b[24m");
+/// ```
+pub trait LazySimpleStyle {
+    /// Lazy version of [SimpleStyle::simple_bold].
+    fn lazy_simple_bold(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, BOLD, cancel::BOLD_OR_FAINT)
+    }
+
+    /// Lazy version of [SimpleStyle::simple_faint].
+    fn lazy_simple_faint(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, FAINT, cancel::BOLD_OR_FAINT)
+    }
+
+    /// Lazy version of [SimpleStyle::italic].
+    fn lazy_italic(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, ITALIC, cancel::ITALIC)
+    }
+
+    /// Lazy version of [SimpleStyle::simple_underline].
+    fn lazy_simple_underline(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, UNDERLINE, cancel::UNDERLINE)
+    }
+
+    /// Lazy version of [SimpleStyle::simple_blink].
+    fn lazy_simple_blink(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, BLINK, cancel::BLINK)
+    }
+
+    /// Lazy version of [SimpleStyle::simple_blink_fast].
+    fn lazy_simple_blink_fast(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, BLINK_FAST, cancel::BLINK)
+    }
+
+    /// Lazy version of [SimpleStyle::inverse].
+    fn lazy_inverse(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, INVERSE, cancel::INVERSE)
+    }
+
+    /// Lazy version of [SimpleStyle::conceal].
+    fn lazy_conceal(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, CONCEAL, cancel::CONCEAL)
+    }
+
+    /// Lazy version of [SimpleStyle::strikethrough].
+    fn lazy_strikethrough(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, STRIKETHROUGH, cancel::STRIKETHROUGH)
+    }
+
+    /// Lazy version of [SimpleStyle::simple_double_underline].
+    fn lazy_simple_double_underline(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, DOUBLE_UNDERLINE, cancel::UNDERLINE)
+    }
+}
+
+/// Lazy versions of the methods in the [SimpleStyleAlias] trait.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::lazy::LazySimpleStyleAlias;
+/// assert_eq!("x".lazy_simple_dim().to_string(), "\x1b[2mx\x1b[22m");
+/// assert_eq!("x".lazy_oblique().to_string(), "\x1b[3mx\x1b[23m");
+/// ```
+pub trait LazySimpleStyleAlias: LazySimpleStyle {
+    /// Lazy version of [SimpleStyleAlias::simple_heavy].
+    fn lazy_simple_heavy(&self) -> Lazy<'_, Self> {
+        self.lazy_simple_bold()
+    }
+
+    /// Lazy version of [SimpleStyleAlias::simple_dim].
+    fn lazy_simple_dim(&self) -> Lazy<'_, Self> {
+        self.lazy_simple_faint()
+    }
+
+    /// Lazy version of [SimpleStyleAlias::oblique].
+    fn lazy_oblique(&self) -> Lazy<'_, Self> {
+        self.lazy_italic()
+    }
+
+    /// Lazy version of [SimpleStyleAlias::negative].
+    fn lazy_negative(&self) -> Lazy<'_, Self> {
+        self.lazy_inverse()
+    }
+
+    /// Lazy version of [SimpleStyleAlias::hidden].
+    fn lazy_hidden(&self) -> Lazy<'_, Self> {
+        self.lazy_conceal()
+    }
+
+    /// Lazy version of [SimpleStyleAlias::delete_line].
+    fn lazy_delete_line(&self) -> Lazy<'_, Self> {
+        self.lazy_strikethrough()
+    }
+}
+
+/// Lazy versions of the methods in the [SimpleForeground] trait.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::lazy::LazySimpleForeground;
+/// assert_eq!("x".lazy_simple_red().to_string(), "\x1b[31mx\x1b[39m");
+/// ```
+pub trait LazySimpleForeground {
+    /// Lazy version of [SimpleForeground::simple_black].
+    fn lazy_simple_black(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BLACK, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_red].
+    fn lazy_simple_red(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::RED, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_green].
+    fn lazy_simple_green(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::GREEN, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_yellow].
+    fn lazy_simple_yellow(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::YELLOW, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_blue].
+    fn lazy_simple_blue(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BLUE, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_magenta].
+    fn lazy_simple_magenta(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::MAGENTA, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_cyan].
+    fn lazy_simple_cyan(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::CYAN, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_white].
+    fn lazy_simple_white(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::WHITE, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_hi_black].
+    fn lazy_simple_hi_black(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BRIGHT_BLACK, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_hi_red].
+    fn lazy_simple_hi_red(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BRIGHT_RED, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_hi_green].
+    fn lazy_simple_hi_green(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BRIGHT_GREEN, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_hi_yellow].
+    fn lazy_simple_hi_yellow(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BRIGHT_YELLOW, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_hi_blue].
+    fn lazy_simple_hi_blue(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BRIGHT_BLUE, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_hi_magenta].
+    fn lazy_simple_hi_magenta(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BRIGHT_MAGENTA, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_hi_cyan].
+    fn lazy_simple_hi_cyan(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BRIGHT_CYAN, cancel::FOREGROUND)
+    }
+
+    /// Lazy version of [SimpleForeground::simple_hi_white].
+    fn lazy_simple_hi_white(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, foreground::BRIGHT_WHITE, cancel::FOREGROUND)
+    }
+}
+
+/// Lazy versions of the methods in the [SimpleBackground] trait.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::lazy::LazySimpleBackground;
+/// assert_eq!("x".lazy_simple_bg_red().to_string(), "\x1b[41mx\x1b[49m");
+/// ```
+pub trait LazySimpleBackground {
+    /// Lazy version of [SimpleBackground::simple_bg_black].
+    fn lazy_simple_bg_black(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BLACK, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_red].
+    fn lazy_simple_bg_red(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::RED, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_green].
+    fn lazy_simple_bg_green(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::GREEN, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_yellow].
+    fn lazy_simple_bg_yellow(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::YELLOW, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_blue].
+    fn lazy_simple_bg_blue(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BLUE, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_magenta].
+    fn lazy_simple_bg_magenta(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::MAGENTA, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_cyan].
+    fn lazy_simple_bg_cyan(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::CYAN, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_white].
+    fn lazy_simple_bg_white(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::WHITE, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_hi_black].
+    fn lazy_simple_bg_hi_black(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BRIGHT_BLACK, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_hi_red].
+    fn lazy_simple_bg_hi_red(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BRIGHT_RED, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_hi_green].
+    fn lazy_simple_bg_hi_green(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BRIGHT_GREEN, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_hi_yellow].
+    fn lazy_simple_bg_hi_yellow(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BRIGHT_YELLOW, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_hi_blue].
+    fn lazy_simple_bg_hi_blue(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BRIGHT_BLUE, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_hi_magenta].
+    fn lazy_simple_bg_hi_magenta(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BRIGHT_MAGENTA, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_hi_cyan].
+    fn lazy_simple_bg_hi_cyan(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BRIGHT_CYAN, cancel::BACKGROUND)
+    }
+
+    /// Lazy version of [SimpleBackground::simple_bg_hi_white].
+    fn lazy_simple_bg_hi_white(&self) -> Lazy<'_, Self> {
+        Lazy::new(self, background::BRIGHT_WHITE, cancel::BACKGROUND)
+    }
+}
+
+/// Lazy versions of the methods in the [SimpleCustomColor] trait,
+/// including RGB mode ([RGBColor]) and color code ([u8]) mode.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{custom_color::RGBColor, lazy::LazySimpleCustomColor};
+///
+/// let hello = " hello ";
+/// let fg = "\x1b[38;5;123m hello \x1b[39m";
+/// assert_eq!(hello.lazy_simple_fg(123).to_string(), fg);
+/// let bg = "\x1b[48;2;143;76;78m hello \x1b[49m";
+/// let color = RGBColor::from((143, 76, 78));
+/// assert_eq!(hello.lazy_simple_bg(color).to_string(), bg);
+/// ```
+pub trait LazySimpleCustomColor<C> {
+    /// Lazy version of [SimpleCustomColor::simple_fg].
+    fn lazy_simple_fg(&self, code: C) -> Lazy<'_, Self, CustomPrefix>;
+
+    /// Lazy version of [SimpleCustomColor::simple_bg].
+    fn lazy_simple_bg(&self, code: C) -> Lazy<'_, Self, CustomPrefix>;
+}
+
+impl<T: AsRef<str> + ?Sized> LazySimpleCustomColor<u8> for T {
+    fn lazy_simple_fg(&self, code: u8) -> Lazy<'_, Self, CustomPrefix> {
+        let prefix = CustomPrefix::foreground(ExtendedColor::Code(code));
+        Lazy::new(self, prefix, cancel::FOREGROUND)
+    }
+
+    fn lazy_simple_bg(&self, code: u8) -> Lazy<'_, Self, CustomPrefix> {
+        let prefix = CustomPrefix::background(ExtendedColor::Code(code));
+        Lazy::new(self, prefix, cancel::BACKGROUND)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> LazySimpleCustomColor<RGBColor> for T {
+    fn lazy_simple_fg(&self, color: RGBColor) -> Lazy<'_, Self, CustomPrefix> {
+        let prefix = CustomPrefix::foreground(ExtendedColor::Rgb(color));
+        Lazy::new(self, prefix, cancel::FOREGROUND)
+    }
+
+    fn lazy_simple_bg(&self, color: RGBColor) -> Lazy<'_, Self, CustomPrefix> {
+        let prefix = CustomPrefix::background(ExtendedColor::Rgb(color));
+        Lazy::new(self, prefix, cancel::BACKGROUND)
+    }
+}
+
+impl<T: AsRef<str> + ?Sized> LazySimpleStyle for T {}
+impl<T: AsRef<str> + ?Sized> LazySimpleStyleAlias for T {}
+impl<T: AsRef<str> + ?Sized> LazySimpleForeground for T {}
+impl<T: AsRef<str> + ?Sized> LazySimpleBackground for T {}
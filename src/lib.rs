@@ -1,13 +1,51 @@
+pub mod border;
+pub mod color_adjust;
+pub mod color_space;
+pub mod compose;
+pub mod contrast;
+pub mod css_color;
 pub mod custom_color;
 pub mod decorate;
+pub mod detect;
 pub mod escape;
 pub mod functions;
+pub mod gradient;
+pub mod html;
+pub mod lazy;
+pub mod markup;
+pub mod palette;
+pub mod panel;
+pub mod progress;
 pub mod render;
+pub mod spinner;
+pub mod table;
+pub mod text;
+pub mod theme;
+pub mod unicode;
 
+pub use border::*;
+pub use color_adjust::*;
+pub use color_space::*;
+pub use compose::*;
+pub use contrast::*;
+pub use css_color::*;
 pub use custom_color::*;
 pub use decorate::*;
 pub use escape::*;
 pub use functions::{
-    simple_background::*, simple_foreground::*, simple_style::*,
-    simple_style_alias::*,
+    render_background::*, render_foreground::*, render_style::*,
+    render_style_alias::*, simple_background::*, simple_foreground::*,
+    simple_style::*, simple_style_alias::*,
 };
+pub use gradient::*;
+pub use html::*;
+pub use lazy::*;
+pub use markup::*;
+pub use panel::*;
+pub use progress::*;
+pub use render::*;
+pub use spinner::*;
+pub use table::*;
+pub use text::*;
+pub use theme::*;
+pub use unicode::*;
@@ -0,0 +1,309 @@
+//! A small inline markup language for decorated strings.
+//!
+//! A tag in square brackets opens a style until the matching `[/]`:
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::markup::markup;
+//!
+//! let raw = "[bold red on blue]Error:[/] [italic]disk full[/]";
+//! assert_eq!(
+//!     markup(raw).unwrap(),
+//!     "\x1b[1;31;44mError:\x1b[22;39;49m \x1b[3mdisk full\x1b[23m",
+//! );
+//! ```
+//!
+//! ## Syntax
+//!
+//! - A tag contains words separated by spaces, each word is one of:
+//!   - Style names: `bold` (or `heavy`), `faint` (or `dim`), `italic`,
+//!     `underline`, `blink`, `blink_fast`, `inverse` (or `reverse`),
+//!     `conceal` (or `hidden`), `strikethrough` (or `strike`)
+//!     and `double_underline`.
+//!   - Foreground colors: the basic color names such as `red`,
+//!     the bright ones such as `hi_red` (or `bright_red`),
+//!     256 color codes such as `208`, and the CSS colors
+//!     (see [css_color](crate::css_color)) such as `#ff8700` or `tomato`.
+//!   - `on` followed by a color, as the background.
+//! - Tags can be nested, and `[/]` closes the innermost one.
+//!   The closing tag can also repeat the content of the opening tag,
+//!   such as `[/bold red]`, which must match exactly.
+//!   Tags still open at the end are closed automatically.
+//! - `\[`, `\]` and `\\` are the literal brackets and backslash.
+//!
+//! Nested styles are restored when the inner ones are closed,
+//! with the minimal escape sequences:
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::markup::markup;
+//!
+//! let nested = markup("[red]a[bold blue]b[/]c[/]").unwrap();
+//! assert_eq!(nested, "\x1b[31ma\x1b[1;34mb\x1b[22;31mc\x1b[39m");
+//! assert_eq!(markup(r"\[not a tag\]").unwrap(), "[not a tag]");
+//! ```
+
+use std::{error::Error, fmt};
+
+use crate::{
+    compose::{Attributes, Color, Style},
+    custom_color::RGBColor,
+    detect::{self, color_depth},
+};
+
+/// Error when parsing markup, with the position
+/// (offset in bytes) of the problem inside the input.
+///
+/// ```rust
+/// use terminal_font::markup::*;
+///
+/// let error = markup("ok [bold purpleish]x").unwrap_err();
+/// assert_eq!(error.position, 9);
+/// assert_eq!(error.kind, MarkupErrorKind::UnknownStyle("purpleish".into()));
+/// assert_eq!(error.to_string(), "unknown style `purpleish` at 9");
+///
+/// let error = markup("x[/]").unwrap_err();
+/// assert_eq!(error.kind, MarkupErrorKind::UnexpectedClose);
+/// assert_eq!(markup("[bold").unwrap_err().position, 0);
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub struct MarkupError {
+    pub position: usize,
+    pub kind: MarkupErrorKind,
+}
+
+#[derive(Clone, Debug, PartialEq, Eq)]
+pub enum MarkupErrorKind {
+    /// A `[` without the matching `]`.
+    UnclosedTag,
+
+    /// A tag without any word, like `[]`.
+    EmptyTag,
+
+    /// Neither a style name nor a color.
+    UnknownStyle(String),
+
+    /// The `on` word is not followed by a color.
+    MissingBackground,
+
+    /// A closing tag without any tag open.
+    UnexpectedClose,
+
+    /// A closing tag that doesn't match the innermost opening tag.
+    MismatchedClose { expected: String, found: String },
+}
+
+impl MarkupError {
+    fn new(position: usize, kind: MarkupErrorKind) -> Self {
+        MarkupError { position, kind }
+    }
+}
+
+impl fmt::Display for MarkupError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match &self.kind {
+            MarkupErrorKind::UnclosedTag => write!(f, "unclosed tag"),
+            MarkupErrorKind::EmptyTag => write!(f, "empty tag"),
+            MarkupErrorKind::UnknownStyle(word) => {
+                write!(f, "unknown style `{word}`")
+            }
+            MarkupErrorKind::MissingBackground => {
+                write!(f, "missing color after `on`")
+            }
+            MarkupErrorKind::UnexpectedClose => {
+                write!(f, "closing tag without open tag")
+            }
+            MarkupErrorKind::MismatchedClose { expected, found } => {
+                write!(f, "closing tag `{found}` doesn't match `{expected}`")
+            }
+        }?;
+        write!(f, " at {}", self.position)
+    }
+}
+
+impl Error for MarkupError {}
+
+/// Render the markup into a decorated string,
+/// see the [markup](mod@crate::markup) mod for the syntax.
+/// Colors are downsampled according to the global color depth,
+/// and only the plain text is kept when colors are disabled.
+pub fn markup(raw: &str) -> Result<String, MarkupError> {
+    let enabled = detect::enabled();
+    let mut result = String::with_capacity(raw.len());
+    let mut stack: Vec<(&str, Style)> = Vec::new();
+    let current = |stack: &[(&str, Style)]| {
+        stack.last().map(|(_, style)| *style).unwrap_or_default()
+    };
+
+    let mut chars = raw.char_indices().peekable();
+    while let Some((index, c)) = chars.next() {
+        match c {
+            '\\' => match chars.peek() {
+                Some(&(_, c @ ('[' | ']' | '\\'))) => {
+                    result.push(c);
+                    chars.next();
+                }
+                _ => result.push('\\'),
+            },
+            '[' => {
+                let end = match raw[index..].find(']') {
+                    Some(length) => index + length,
+                    None => {
+                        let kind = MarkupErrorKind::UnclosedTag;
+                        return Err(MarkupError::new(index, kind));
+                    }
+                };
+                while chars.next_if(|&(i, _)| i <= end).is_some() {}
+
+                let tag = &raw[index + 1..end];
+                let from = current(&stack);
+                match tag.strip_prefix('/') {
+                    Some(closing) => {
+                        let Some((opening, _)) = stack.pop() else {
+                            let kind = MarkupErrorKind::UnexpectedClose;
+                            return Err(MarkupError::new(index, kind));
+                        };
+                        let (closing, opening) =
+                            (closing.trim(), opening.trim());
+                        if !closing.is_empty() && closing != opening {
+                            let kind = MarkupErrorKind::MismatchedClose {
+                                expected: opening.to_string(),
+                                found: closing.to_string(),
+                            };
+                            return Err(MarkupError::new(index, kind));
+                        }
+                    }
+                    None => {
+                        let style = parse_tag(tag, index + 1)?;
+                        stack.push((tag, from.merge(style)));
+                    }
+                }
+                if enabled {
+                    result.push_str(&transition(from, current(&stack)));
+                }
+            }
+            c => result.push(c),
+        }
+    }
+    if enabled {
+        result.push_str(&transition(current(&stack), Style::new()));
+    }
+    Ok(result)
+}
+
+/// Parse the content of an opening tag,
+/// the offset is the position of the content inside the input.
+fn parse_tag(tag: &str, offset: usize) -> Result<Style, MarkupError> {
+    let mut words = tag
+        .split(' ')
+        .scan(offset, |position, word| {
+            let start = *position;
+            *position += word.len() + 1;
+            Some((start, word))
+        })
+        .filter(|(_, word)| !word.is_empty());
+
+    let mut style = Style::new();
+    let mut empty = true;
+    while let Some((position, word)) = words.next() {
+        empty = false;
+        let unknown = || {
+            let kind = MarkupErrorKind::UnknownStyle(word.to_string());
+            MarkupError::new(position, kind)
+        };
+        if word == "on" {
+            let Some((position, word)) = words.next() else {
+                let kind = MarkupErrorKind::MissingBackground;
+                return Err(MarkupError::new(position, kind));
+            };
+            let color = parse_color(word).ok_or_else(|| {
+                let kind = MarkupErrorKind::UnknownStyle(word.to_string());
+                MarkupError::new(position, kind)
+            })?;
+            style = style.bg(color);
+        } else if let Some(attributes) = parse_attributes(word) {
+            style = style.attributes(attributes);
+        } else {
+            style = style.fg(parse_color(word).ok_or_else(unknown)?);
+        }
+    }
+    match empty {
+        true => Err(MarkupError::new(offset - 1, MarkupErrorKind::EmptyTag)),
+        false => Ok(style),
+    }
+}
+
+fn parse_attributes(word: &str) -> Option<Attributes> {
+    Some(match word {
+        "bold" | "heavy" => Attributes::BOLD,
+        "faint" | "dim" => Attributes::FAINT,
+        "italic" => Attributes::ITALIC,
+        "underline" => Attributes::UNDERLINE,
+        "blink" => Attributes::BLINK,
+        "blink_fast" => Attributes::BLINK_FAST,
+        "inverse" | "reverse" => Attributes::INVERSE,
+        "conceal" | "hidden" => Attributes::CONCEAL,
+        "strikethrough" | "strike" => Attributes::STRIKETHROUGH,
+        "double_underline" => Attributes::DOUBLE_UNDERLINE,
+        _ => return None,
+    })
+}
+
+fn parse_color(word: &str) -> Option<Color> {
+    const NAMES: [&str; 8] = [
+        "black", "red", "green", "yellow", "blue", "magenta", "cyan", "white",
+    ];
+    let (name, offset) = match word
+        .strip_prefix("hi_")
+        .or_else(|| word.strip_prefix("bright_"))
+    {
+        Some(name) => (name, 8),
+        None => (word, 0),
+    };
+    if let Some(index) = NAMES.iter().position(|&n| n == name) {
+        return Some(Color::BASIC[index + offset]);
+    }
+    if let Ok(code) = word.parse::<u8>() {
+        return Some(Color::Code(code));
+    }
+    word.parse::<RGBColor>().ok().map(Color::Rgb)
+}
+
+/// The minimal escape sequence to change the active style
+/// from one to another.
+fn transition(from: Style, to: Style) -> String {
+    let removed = from.attributes.difference(to.attributes);
+    let cancels: Vec<u8> = removed.cancel_codes().collect();
+    let mut params: Vec<String> =
+        cancels.iter().map(|code| code.to_string()).collect();
+    // Attributes sharing the cancel codes should be enabled again.
+    params.extend(
+        Attributes::TABLE
+            .into_iter()
+            .filter(|(attribute, _, cancel)| {
+                to.attributes.contains(*attribute)
+                    && (!from.attributes.contains(*attribute)
+                        || cancels.contains(cancel))
+            })
+            .map(|(_, code, _)| code.to_string()),
+    );
+    let depth = color_depth();
+    if from.foreground != to.foreground {
+        params.push(match to.foreground {
+            Some(color) => color.downsample(depth).foreground_params(),
+            None => "39".to_string(),
+        });
+    }
+    if from.background != to.background {
+        params.push(match to.background {
+            Some(color) => color.downsample(depth).background_params(),
+            None => "49".to_string(),
+        });
+    }
+    match params.is_empty() {
+        true => String::new(),
+        false => format!("\x1b[{}m", params.join(";")),
+    }
+}
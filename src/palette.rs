@@ -0,0 +1,107 @@
+//! The standard xterm 256 color palette.
+//!
+//! The 256 color codes (as used by [SimpleCustomColor] with [u8])
+//! consist of three parts:
+//!
+//! 1. `0..=15`: the 16 basic colors ([SYSTEM]),
+//!    the same as the ones in the [foreground](crate::foreground)
+//!    and [background](crate::background) mods.
+//!    Their actual values are usually customized by the terminal themes,
+//!    and the default values of xterm are used here.
+//! 2. `16..=231`: the 6x6x6 color cube, each channel in [CUBE_LEVELS].
+//! 3. `232..=255`: the 24 steps grayscale ramp, from `8` to `238`.
+//!
+//! ```rust
+//! use terminal_font::{custom_color::RGBColor, palette::*};
+//!
+//! assert_eq!(XTERM[196], RGBColor::from(0xff0000));
+//! assert_eq!(RGBColor::from_code(33), RGBColor::from(0x0087ff));
+//! assert_eq!(RGBColor::from_code(244), RGBColor::from(0x808080));
+//! ```
+
+use crate::custom_color::RGBColor;
+
+#[allow(unused_imports)] // Docs only.
+use crate::custom_color::SimpleCustomColor;
+
+/// Default values of the 16 basic colors in xterm,
+/// in the order of their escape codes.
+pub const SYSTEM: [RGBColor; 16] = [
+    rgb(0, 0, 0),
+    rgb(205, 0, 0),
+    rgb(0, 205, 0),
+    rgb(205, 205, 0),
+    rgb(0, 0, 238),
+    rgb(205, 0, 205),
+    rgb(0, 205, 205),
+    rgb(229, 229, 229),
+    rgb(127, 127, 127),
+    rgb(255, 0, 0),
+    rgb(0, 255, 0),
+    rgb(255, 255, 0),
+    rgb(92, 92, 255),
+    rgb(255, 0, 255),
+    rgb(0, 255, 255),
+    rgb(255, 255, 255),
+];
+
+/// Channel levels of the 6x6x6 color cube.
+pub const CUBE_LEVELS: [u8; 6] = [0, 95, 135, 175, 215, 255];
+
+/// The whole xterm 256 color palette, indexed by the color code.
+pub const XTERM: [RGBColor; 256] = {
+    let mut palette = [rgb(0, 0, 0); 256];
+    let mut code = 0;
+    while code < 256 {
+        palette[code] = code_to_rgb(code as u8);
+        code += 1;
+    }
+    palette
+};
+
+const fn rgb(r: u8, g: u8, b: u8) -> RGBColor {
+    RGBColor { r, g, b }
+}
+
+/// Convert a 256 color code into its [RGBColor] in the xterm palette.
+/// It's the const version of [RGBColor::from_code].
+pub const fn code_to_rgb(code: u8) -> RGBColor {
+    match code {
+        0..=15 => SYSTEM[code as usize],
+        16..=231 => {
+            let index = code - 16;
+            rgb(
+                CUBE_LEVELS[(index / 36) as usize],
+                CUBE_LEVELS[(index / 6 % 6) as usize],
+                CUBE_LEVELS[(index % 6) as usize],
+            )
+        }
+        _ => {
+            let value = 8 + (code - 232) * 10;
+            rgb(value, value, value)
+        }
+    }
+}
+
+impl RGBColor {
+    /// Convert a 256 color code into its [RGBColor] in the xterm palette.
+    /// See the [palette](crate::palette) mod for more details.
+    ///
+    /// ```rust
+    /// use terminal_font::custom_color::RGBColor;
+    ///
+    /// assert_eq!(RGBColor::from_code(1), RGBColor::from((205, 0, 0)));
+    /// assert_eq!(RGBColor::from_code(16), RGBColor::from((0, 0, 0)));
+    /// assert_eq!(RGBColor::from_code(231), RGBColor::from((255, 255, 255)));
+    /// assert_eq!(RGBColor::from_code(232), RGBColor::from((8, 8, 8)));
+    /// assert_eq!(RGBColor::from_code(255), RGBColor::from((238, 238, 238)));
+    ///
+    /// // Round trip inside the color cube and the grayscale ramp.
+    /// for code in 16..=255 {
+    ///     assert_eq!(RGBColor::from_code(code).to_ansi256(), code);
+    /// }
+    /// ```
+    pub fn from_code(code: u8) -> RGBColor {
+        XTERM[code as usize]
+    }
+}
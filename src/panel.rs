@@ -0,0 +1,215 @@
+//! Bordered boxes around multi-line decorated content,
+//! with optional title and footer inside the border.
+//!
+//! ```rust
+//! use terminal_font::{border::Border, detect::*, panel::Panel};
+//!
+//! set_color_depth(ColorDepth::None);
+//! let panel = Panel::new("hello\nworld!")
+//!     .title("Hi")
+//!     .footer("ok")
+//!     .border(Border::ROUNDED);
+//! assert_eq!(panel.render(), "\
+//! ╭─ Hi ───╮
+//! │ hello  │
+//! │ world! │
+//! ╰─ ok ───╯");
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    border::Border,
+    compose::{Color, Style},
+    text::{align, truncate, word_wrap, Alignment},
+    unicode::display_width,
+};
+
+/// A box drawn around the content.
+///
+/// The inner width is the width of the widest line of the content
+/// (or wide enough for the title and the footer),
+/// unless it's fixed by [Panel::width], which wraps the content.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{border::Border, compose::*, panel::Panel};
+///
+/// let panel = Panel::new("done")
+///     .border(Border::ASCII)
+///     .border_color(Color::Green)
+///     .padding(0);
+/// assert_eq!(panel.render(), "\
+/// \x1b[32m+----+\x1b[39m
+/// \x1b[32m|\x1b[39mdone\x1b[32m|\x1b[39m
+/// \x1b[32m+----+\x1b[39m");
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Panel {
+    pub content: String,
+    pub title: Option<String>,
+    pub footer: Option<String>,
+    pub alignment: Alignment,
+    pub border: Border,
+    pub border_style: Style,
+
+    /// Spaces between the content and the left and right borders.
+    pub padding: usize,
+
+    /// Empty lines between the content and the top and bottom borders.
+    pub vertical_padding: usize,
+
+    /// Fixed inner width, excluding the padding.
+    pub width: Option<usize>,
+}
+
+impl Panel {
+    /// A panel with [Border::LIGHT] and one space of padding.
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Panel {
+            content: content.into(),
+            title: None,
+            footer: None,
+            alignment: Alignment::Left,
+            border: Border::LIGHT,
+            border_style: Style::new(),
+            padding: 1,
+            vertical_padding: 0,
+            width: None,
+        }
+    }
+
+    /// Title inside the top border, which might be decorated.
+    pub fn title<T: Into<String>>(mut self, title: T) -> Self {
+        self.title = Some(title.into());
+        self
+    }
+
+    /// Footer inside the bottom border, which might be decorated.
+    pub fn footer<T: Into<String>>(mut self, footer: T) -> Self {
+        self.footer = Some(footer.into());
+        self
+    }
+
+    /// Alignment of the title and the footer inside the borders.
+    pub fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub fn border(mut self, border: Border) -> Self {
+        self.border = border;
+        self
+    }
+
+    /// Style of the border lines, independent of the content.
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    /// Foreground color of the border lines,
+    /// either a basic [Color] or an [RGBColor](crate::RGBColor).
+    pub fn border_color<T: Into<Color>>(mut self, color: T) -> Self {
+        self.border_style = self.border_style.fg(color);
+        self
+    }
+
+    pub fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    pub fn vertical_padding(mut self, padding: usize) -> Self {
+        self.vertical_padding = padding;
+        self
+    }
+
+    /// Fix the inner width (excluding the padding),
+    /// and wrap the content into it. Zero is treated as one.
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = Some(width.max(1));
+        self
+    }
+
+    /// Render the panel into lines joined by `\n`,
+    /// without a trailing line break.
+    pub fn render(&self) -> String {
+        let lines = word_wrap(&self.content, self.width.unwrap_or(usize::MAX));
+        let width = self.width.unwrap_or_else(|| {
+            let label = |text: &Option<String>| match text {
+                Some(text) => display_width(text) + 4,
+                None => 0,
+            };
+            lines
+                .iter()
+                .map(display_width)
+                .max()
+                .unwrap_or(0)
+                .max(label(&self.title).saturating_sub(self.padding * 2))
+                .max(label(&self.footer).saturating_sub(self.padding * 2))
+        });
+        let span = width + self.padding * 2;
+
+        let paint = |raw: String| self.border_style.simple_apply(raw);
+        let vertical = paint(self.border.vertical.to_string());
+        let padding = " ".repeat(self.padding);
+        let empty = String::new();
+        let blank = std::iter::repeat_n(&empty, self.vertical_padding);
+
+        let mut result = vec![self.edge(
+            self.border.top_left,
+            self.border.top_right,
+            self.title.as_deref(),
+            span,
+        )];
+        for line in blank.clone().chain(&lines).chain(blank) {
+            let line = align(line, width, Alignment::Left, ' ');
+            result
+                .push(format!("{vertical}{padding}{line}{padding}{vertical}"));
+        }
+        result.push(self.edge(
+            self.border.bottom_left,
+            self.border.bottom_right,
+            self.footer.as_deref(),
+            span,
+        ));
+        result.join("\n")
+    }
+
+    /// Horizontal border line with an optional label inside,
+    /// the label is truncated if there's no enough space.
+    fn edge(
+        &self,
+        left: char,
+        right: char,
+        label: Option<&str>,
+        span: usize,
+    ) -> String {
+        let horizontal = |count: usize| {
+            std::iter::repeat_n(self.border.horizontal, count)
+                .collect::<String>()
+        };
+        let paint = |raw: String| self.border_style.simple_apply(raw);
+        let label = match label {
+            Some(label) if span >= 5 => {
+                format!(" {} ", truncate(label, span - 4))
+            }
+            _ => return paint(format!("{left}{}{right}", horizontal(span))),
+        };
+        let (before, after) =
+            self.alignment.padding(display_width(&label), span - 2);
+        format!(
+            "{}{label}{}",
+            paint(format!("{left}{}", horizontal(before + 1))),
+            paint(format!("{}{right}", horizontal(after + 1))),
+        )
+    }
+}
+
+impl Display for Panel {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
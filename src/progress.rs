@@ -0,0 +1,255 @@
+//! Progress bars rendered into strings.
+//!
+//! The bar is drawn with the Unicode eighth blocks (like `▌`),
+//! so that the progress has the precision of an eighth column.
+//! Rendering is pure: the caller passes the current progress
+//! and the elapsed time, which keeps it testable without a terminal.
+//!
+//! ```rust
+//! use std::time::Duration;
+//! use terminal_font::{detect::*, progress::ProgressBar};
+//!
+//! set_color_depth(ColorDepth::None);
+//! let bar = ProgressBar::new(100).width(10);
+//! let line = bar.render(45, Duration::from_secs(9));
+//! assert_eq!(line, "████▌       45% 5.0/s ETA 00:11");
+//! let redraw = bar.redraw(45, Duration::from_secs(9));
+//! assert_eq!(redraw, format!("\r{line}\x1b[K"));
+//! ```
+
+use std::time::Duration;
+
+use crate::{
+    compose::{Color, Style},
+    gradient::Gradient,
+};
+
+/// Partial blocks from one eighth to seven eighths.
+const EIGHTHS: [char; 8] = [' ', '▏', '▎', '▍', '▌', '▋', '▊', '▉'];
+
+/// Color of the filled part of a [ProgressBar].
+#[derive(Clone, Debug, PartialEq)]
+pub enum Fill {
+    /// A single color, such as the basic ones
+    /// used by [SimpleForeground](crate::SimpleForeground).
+    Solid(Color),
+
+    /// Each column takes its color from the position of the whole bar,
+    /// so the gradient reveals as the progress grows.
+    Gradient(Gradient),
+}
+
+impl Fill {
+    fn color_at(&self, position: f64) -> Option<Color> {
+        match self {
+            Fill::Solid(color) => Some(*color),
+            Fill::Gradient(gradient) => {
+                gradient.color_at(position).map(Color::Rgb)
+            }
+        }
+    }
+}
+
+/// Layout and colors of a progress bar.
+#[derive(Clone, Debug, PartialEq)]
+pub struct ProgressBar {
+    pub total: u64,
+
+    /// Columns of the bar, excluding the statistics.
+    pub width: usize,
+    pub fill: Fill,
+
+    /// Background color of the empty part,
+    /// the same escape as the [SimpleBackground](crate::SimpleBackground)
+    /// methods for the basic colors.
+    pub empty: Option<Color>,
+    pub percentage: bool,
+    pub rate: bool,
+    pub eta: bool,
+}
+
+impl ProgressBar {
+    /// A bar of 30 columns filled with green on bright black,
+    /// with percentage, rate and ETA.
+    pub fn new(total: u64) -> Self {
+        ProgressBar {
+            total,
+            width: 30,
+            fill: Fill::Solid(Color::Green),
+            empty: Some(Color::BrightBlack),
+            percentage: true,
+            rate: true,
+            eta: true,
+        }
+    }
+
+    pub fn width(mut self, width: usize) -> Self {
+        self.width = width;
+        self
+    }
+
+    /// Fill with a single color.
+    pub fn fill<T: Into<Color>>(mut self, color: T) -> Self {
+        self.fill = Fill::Solid(color.into());
+        self
+    }
+
+    /// Fill with a gradient across the whole bar.
+    pub fn gradient(mut self, gradient: Gradient) -> Self {
+        self.fill = Fill::Gradient(gradient);
+        self
+    }
+
+    /// Background color of the empty part, or [None] for no color.
+    pub fn empty(mut self, color: Option<Color>) -> Self {
+        self.empty = color;
+        self
+    }
+
+    pub fn percentage(mut self, show: bool) -> Self {
+        self.percentage = show;
+        self
+    }
+
+    pub fn rate(mut self, show: bool) -> Self {
+        self.rate = show;
+        self
+    }
+
+    pub fn eta(mut self, show: bool) -> Self {
+        self.eta = show;
+        self
+    }
+
+    /// Ratio of the progress, in `0.0..=1.0`.
+    /// A bar with zero total is always complete.
+    pub fn ratio(&self, current: u64) -> f64 {
+        match self.total {
+            0 => 1.0,
+            total => current.min(total) as f64 / total as f64,
+        }
+    }
+
+    /// Only the bar, without the statistics.
+    ///
+    /// ```rust
+    /// # use terminal_font::detect::{set_color_depth, ColorDepth};
+    /// # set_color_depth(ColorDepth::TrueColor);
+    /// use terminal_font::{compose::Color, progress::ProgressBar};
+    ///
+    /// let bar = ProgressBar::new(100).width(4);
+    /// let green = "\x1b[32m██\x1b[39m";
+    /// let gray = "\x1b[100m  \x1b[49m";
+    /// assert_eq!(bar.bar(50), format!("{green}{gray}"));
+    ///
+    /// let bar = bar.fill(Color::Red).empty(None);
+    /// assert_eq!(bar.bar(55), "\x1b[31m██▎\x1b[39m ");
+    /// ```
+    pub fn bar(&self, current: u64) -> String {
+        let eighths = (self.ratio(current) * (self.width * 8) as f64).round();
+        let (full, partial) = (eighths as usize / 8, eighths as usize % 8);
+        let position = |column: usize| match self.width {
+            0 | 1 => 0.0,
+            width => column as f64 / (width - 1) as f64,
+        };
+
+        // Merge the consecutive columns with the same style.
+        let mut runs: Vec<(Style, String)> = Vec::new();
+        for column in 0..self.width {
+            let color = self.fill.color_at(position(column));
+            let (c, style) = if column < full {
+                ('█', fill_style(Style::new(), color))
+            } else if column == full && partial > 0 {
+                let style = empty_style(Style::new(), self.empty);
+                (EIGHTHS[partial], fill_style(style, color))
+            } else {
+                (' ', empty_style(Style::new(), self.empty))
+            };
+            match runs.last_mut() {
+                Some((last, run)) if *last == style => run.push(c),
+                _ => runs.push((style, c.to_string())),
+            }
+        }
+        runs.into_iter()
+            .map(|(style, run)| style.simple_apply(run))
+            .collect()
+    }
+
+    /// The bar followed by the enabled statistics.
+    /// The rate is the count of items per second,
+    /// and the ETA is estimated from the average rate.
+    /// A bar with zero total is complete, with nothing remaining.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use terminal_font::{detect::*, progress::ProgressBar};
+    ///
+    /// set_color_depth(ColorDepth::None);
+    /// let bar = ProgressBar::new(0).width(4);
+    /// let line = bar.render(6, Duration::from_secs(2));
+    /// assert_eq!(line, "████ 100% 3.0/s ETA 00:00");
+    /// ```
+    pub fn render(&self, current: u64, elapsed: Duration) -> String {
+        let mut line = self.bar(current);
+        let seconds = elapsed.as_secs_f64();
+        let current = match self.total {
+            0 => current,
+            total => current.min(total),
+        };
+        if self.percentage {
+            let percentage = (self.ratio(current) * 100.0).floor();
+            line.push_str(&format!(" {percentage:>3}%"));
+        }
+        if self.rate {
+            match seconds > 0.0 {
+                true => {
+                    let rate = current as f64 / seconds;
+                    line.push_str(&format!(" {rate:.1}/s"));
+                }
+                false => line.push_str(" --/s"),
+            }
+        }
+        if self.eta {
+            let eta = match (self.total, current) {
+                (0, _) => clock(0),
+                (_, 0) => "--:--".to_string(),
+                _ => {
+                    let remaining = (self.total - current) as f64;
+                    clock((seconds * remaining / current as f64).round() as u64)
+                }
+            };
+            line.push_str(&format!(" ETA {eta}"));
+        }
+        line
+    }
+
+    /// Same as [ProgressBar::render], but starts with a carriage return
+    /// and ends with erasing the rest of the line,
+    /// so that printing it (without a line break) redraws the bar in place.
+    pub fn redraw(&self, current: u64, elapsed: Duration) -> String {
+        format!("\r{}\x1b[K", self.render(current, elapsed))
+    }
+}
+
+fn fill_style(style: Style, color: Option<Color>) -> Style {
+    match color {
+        Some(color) => style.fg(color),
+        None => style,
+    }
+}
+
+fn empty_style(style: Style, color: Option<Color>) -> Style {
+    match color {
+        Some(color) => style.bg(color),
+        None => style,
+    }
+}
+
+/// Format seconds as `mm:ss`, or `h:mm:ss` when longer than an hour.
+fn clock(seconds: u64) -> String {
+    let (hours, minutes) = (seconds / 3600, seconds / 60 % 60);
+    match hours {
+        0 => format!("{minutes:02}:{:02}", seconds % 60),
+        _ => format!("{hours}:{minutes:02}:{:02}", seconds % 60),
+    }
+}
@@ -1 +1,409 @@
+//! Safe decorations that won't be broken by inner escape sequences.
+//!
+//! Methods and functions in the [decorate] mod (those with `simple` prefix)
+//! only wrap a prefix and a suffix around the raw string.
+//! Once the raw string has already been decorated,
+//! the cancel sequence inside it (such as `\x1b[22m` or `\x1b[0m`)
+//! will also cancel the outer decoration,
+//! and the rest of the string will lose its style.
+//!
+//! Methods in this mod will parse the inner structure of the raw string,
+//! and re-emit the outer prefix after every inner escape sequence
+//! that cancels it. So that the outer style will keep working
+//! until the end of the whole string.
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::{decorate::*, render::*};
+//!
+//! let inner = "b".simple_bold();
+//! let raw = format!("a{}c", inner);
+//! assert_eq!(raw.simple_faint(), "\x1b[2ma\x1b[1mb\x1b[22mc\x1b[22m");
+//! assert_eq!(raw.faint(), "\x1b[2ma\x1b[1mb\x1b[22m\x1b[2mc\x1b[22m");
+//! ```
+//!
+//! ## Performance
+//!
+//! The raw string will be scanned once for every decoration.
+//! If the content is predicated and contains no escape sequence,
+//! the `simple` methods in the [decorate] mod are faster.
 
+use crate::{
+    detect,
+    escape::{
+        background, cancel, foreground, style::*, tokenize, SgrAttribute, Token,
+    },
+};
+
+#[allow(unused_imports)] // Docs only.
+use crate::decorate;
+
+pub trait Render {
+    /// Wrap a prefix and a suffix, and re-emit the prefix
+    /// after every inner SGR escape sequence that cancels it.
+    ///
+    /// An inner sequence is considered to cancel the prefix
+    /// when it contains the reset code (`0` or empty)
+    /// or any code inside the `suffix`.
+    /// The prefix won't be re-emitted when there's nothing after
+    /// the inner sequence, because the suffix follows immediately.
+    /// And the raw string will be returned unchanged
+    /// when colors are disabled according to the [detect] mod.
+    ///
+    /// ```rust
+    /// # use terminal_font::detect::{set_color_depth, ColorDepth};
+    /// # set_color_depth(ColorDepth::TrueColor);
+    /// use terminal_font::render::Render;
+    ///
+    /// let (prefix, suffix) = ("\x1b[1m", "\x1b[22m");
+    /// let raw = "a\x1b[0mb";
+    /// let expected = "\x1b[1ma\x1b[0m\x1b[1mb\x1b[22m";
+    /// assert_eq!(raw.render(prefix, suffix), expected);
+    ///
+    /// let raw = "a\x1b[0m";
+    /// assert_eq!(raw.render(prefix, suffix), "\x1b[1ma\x1b[0m\x1b[22m");
+    /// ```
+    ///
+    /// Codes inside extended colors (`38;5;n` and `38;2;r;g;b`)
+    /// won't be treated as cancels:
+    ///
+    /// ```rust
+    /// # use terminal_font::detect::{set_color_depth, ColorDepth};
+    /// # set_color_depth(ColorDepth::TrueColor);
+    /// use terminal_font::render::Render;
+    ///
+    /// let raw = "a\x1b[38;5;22mb";
+    /// let expected = "\x1b[1ma\x1b[38;5;22mb\x1b[22m";
+    /// assert_eq!(raw.render("\x1b[1m", "\x1b[22m"), expected);
+    /// ```
+    fn render<T: AsRef<str>, U: AsRef<str>>(
+        &self,
+        prefix: T,
+        suffix: U,
+    ) -> String;
+}
+
+impl<T: AsRef<str>> Render for T {
+    fn render<U: AsRef<str>, V: AsRef<str>>(
+        &self,
+        prefix: U,
+        suffix: V,
+    ) -> String {
+        let (raw, prefix, suffix) =
+            (self.as_ref(), prefix.as_ref(), suffix.as_ref());
+        if !detect::enabled() {
+            return raw.to_string();
+        }
+        let cancels: Vec<u16> = sgr_codes(suffix).collect();
+
+        let mut handler =
+            String::with_capacity(prefix.len() + raw.len() + suffix.len());
+        handler.push_str(prefix);
+        let mut tokens = tokenize(raw).peekable();
+        while let Some(token) = tokens.next() {
+            handler.push_str(token.raw());
+            let cancelled = sgr_codes(token.raw())
+                .any(|code| code == 0 || cancels.contains(&code));
+            if cancelled && tokens.peek().is_some() {
+                handler.push_str(prefix);
+            }
+        }
+        handler.push_str(suffix);
+        handler
+    }
+}
+
+/// Iterate the single codes of all SGR escape sequences inside the string,
+/// skipping extended colors such as `38;5;n` and `38;2;r;g;b`.
+fn sgr_codes(raw: &str) -> impl Iterator<Item = u16> + '_ {
+    tokenize(raw)
+        .filter_map(|token| match token {
+            Token::Sgr { params, .. } => Some(params.attributes()),
+            _ => None,
+        })
+        .flatten()
+        .filter_map(|attribute| match attribute {
+            SgrAttribute::Code(code) => Some(code),
+            _ => None,
+        })
+}
+
+/// Encapsulation on the [Render] trait with
+/// commonly used escape codes for style decorations.
+///
+/// Those methods are the safe versions of the `simple` methods
+/// inside the [decorate::SimpleStyle] trait.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::render::RenderStyle;
+/// assert_eq!(" hello ".bold(), "\x1b[1m hello \x1b[22m");
+/// assert_eq!(" hello ".faint(), "\x1b[2m hello \x1b[22m");
+/// assert_eq!(" hello ".underline(), "\x1b[4m hello \x1b[24m");
+/// assert_eq!(" hello ".blink(), "\x1b[5m hello \x1b[25m");
+/// assert_eq!(" hello ".blink_fast(), "\x1b[6m hello \x1b[25m");
+/// assert_eq!(" hello ".double_underline(), "\x1b[21m hello \x1b[24m");
+/// ```
+pub trait RenderStyle: Render {
+    /// Also known as [RenderStyleAlias::heavy].
+    /// See the documentation of the trait: [RenderStyle].
+    fn bold(&self) -> String {
+        self.render(BOLD, cancel::BOLD_OR_FAINT)
+    }
+
+    /// Also known as [RenderStyleAlias::dim].
+    /// See the documentation of the trait: [RenderStyle].
+    fn faint(&self) -> String {
+        self.render(FAINT, cancel::BOLD_OR_FAINT)
+    }
+
+    /// See the documentation of the trait: [RenderStyle].
+    fn underline(&self) -> String {
+        self.render(UNDERLINE, cancel::UNDERLINE)
+    }
+
+    /// Usually unsupported by build-in terminals of common editors.
+    /// See the documentation of the trait: [RenderStyle].
+    fn blink(&self) -> String {
+        self.render(BLINK, cancel::BLINK)
+    }
+
+    /// Usually unsupported by common terminals.
+    /// See the documentation of the trait: [RenderStyle].
+    fn blink_fast(&self) -> String {
+        self.render(BLINK_FAST, cancel::BLINK)
+    }
+
+    /// Usually unsupported by common terminals,
+    /// and sometimes displayed as a thick underline.
+    /// See the documentation of the trait: [RenderStyle].
+    fn double_underline(&self) -> String {
+        self.render(DOUBLE_UNDERLINE, cancel::UNDERLINE)
+    }
+}
+
+/// Aliases for some of the methods in the [RenderStyle] trait.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::render::RenderStyleAlias;
+/// assert_eq!(" hello ".heavy(), "\x1b[1m hello \x1b[22m");
+/// assert_eq!(" hello ".dim(), "\x1b[2m hello \x1b[22m");
+/// ```
+pub trait RenderStyleAlias: RenderStyle {
+    /// Alias of [RenderStyle::bold].
+    fn heavy(&self) -> String {
+        self.bold()
+    }
+
+    /// Alias of [RenderStyle::faint].
+    fn dim(&self) -> String {
+        self.faint()
+    }
+}
+
+/// Encapsulation on the [Render] trait with
+/// commonly used escape codes for foreground color decorations.
+///
+/// Those methods are the safe versions of the `simple` methods
+/// inside the [decorate::SimpleForeground] trait.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::render::RenderForeground;
+/// assert_eq!(" hello ".black(), "\x1b[30m hello \x1b[39m");
+/// assert_eq!(" hello ".red(), "\x1b[31m hello \x1b[39m");
+/// assert_eq!(" hello ".green(), "\x1b[32m hello \x1b[39m");
+/// assert_eq!(" hello ".yellow(), "\x1b[33m hello \x1b[39m");
+/// assert_eq!(" hello ".blue(), "\x1b[34m hello \x1b[39m");
+/// assert_eq!(" hello ".magenta(), "\x1b[35m hello \x1b[39m");
+/// assert_eq!(" hello ".cyan(), "\x1b[36m hello \x1b[39m");
+/// assert_eq!(" hello ".white(), "\x1b[37m hello \x1b[39m");
+/// assert_eq!(" hello ".hi_black(), "\x1b[90m hello \x1b[39m");
+/// assert_eq!(" hello ".hi_red(), "\x1b[91m hello \x1b[39m");
+/// assert_eq!(" hello ".hi_green(), "\x1b[92m hello \x1b[39m");
+/// assert_eq!(" hello ".hi_yellow(), "\x1b[93m hello \x1b[39m");
+/// assert_eq!(" hello ".hi_blue(), "\x1b[94m hello \x1b[39m");
+/// assert_eq!(" hello ".hi_magenta(), "\x1b[95m hello \x1b[39m");
+/// assert_eq!(" hello ".hi_cyan(), "\x1b[96m hello \x1b[39m");
+/// assert_eq!(" hello ".hi_white(), "\x1b[97m hello \x1b[39m");
+/// ```
+///
+/// Inner foreground colors will work in their own range,
+/// and the outer color will be recovered after them:
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{decorate::*, render::*};
+///
+/// let raw = format!("a{}c", "b".simple_green());
+/// assert_eq!(raw.red(), "\x1b[31ma\x1b[32mb\x1b[39m\x1b[31mc\x1b[39m");
+/// ```
+pub trait RenderForeground: Render {
+    fn black(&self) -> String {
+        self.render(foreground::BLACK, cancel::FOREGROUND)
+    }
+
+    fn red(&self) -> String {
+        self.render(foreground::RED, cancel::FOREGROUND)
+    }
+
+    fn green(&self) -> String {
+        self.render(foreground::GREEN, cancel::FOREGROUND)
+    }
+
+    fn yellow(&self) -> String {
+        self.render(foreground::YELLOW, cancel::FOREGROUND)
+    }
+
+    fn blue(&self) -> String {
+        self.render(foreground::BLUE, cancel::FOREGROUND)
+    }
+
+    fn magenta(&self) -> String {
+        self.render(foreground::MAGENTA, cancel::FOREGROUND)
+    }
+
+    fn cyan(&self) -> String {
+        self.render(foreground::CYAN, cancel::FOREGROUND)
+    }
+
+    fn white(&self) -> String {
+        self.render(foreground::WHITE, cancel::FOREGROUND)
+    }
+
+    fn hi_black(&self) -> String {
+        self.render(foreground::BRIGHT_BLACK, cancel::FOREGROUND)
+    }
+
+    fn hi_red(&self) -> String {
+        self.render(foreground::BRIGHT_RED, cancel::FOREGROUND)
+    }
+
+    fn hi_green(&self) -> String {
+        self.render(foreground::BRIGHT_GREEN, cancel::FOREGROUND)
+    }
+
+    fn hi_yellow(&self) -> String {
+        self.render(foreground::BRIGHT_YELLOW, cancel::FOREGROUND)
+    }
+
+    fn hi_blue(&self) -> String {
+        self.render(foreground::BRIGHT_BLUE, cancel::FOREGROUND)
+    }
+
+    fn hi_magenta(&self) -> String {
+        self.render(foreground::BRIGHT_MAGENTA, cancel::FOREGROUND)
+    }
+
+    fn hi_cyan(&self) -> String {
+        self.render(foreground::BRIGHT_CYAN, cancel::FOREGROUND)
+    }
+
+    fn hi_white(&self) -> String {
+        self.render(foreground::BRIGHT_WHITE, cancel::FOREGROUND)
+    }
+}
+
+/// Encapsulation on the [Render] trait with
+/// commonly used escape codes for background color decorations.
+///
+/// Those methods are the safe versions of the `simple` methods
+/// inside the [decorate::SimpleBackground] trait.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::render::RenderBackground;
+/// assert_eq!(" hello ".bg_black(), "\x1b[40m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_red(), "\x1b[41m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_green(), "\x1b[42m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_yellow(), "\x1b[43m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_blue(), "\x1b[44m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_magenta(), "\x1b[45m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_cyan(), "\x1b[46m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_white(), "\x1b[47m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_hi_black(), "\x1b[100m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_hi_red(), "\x1b[101m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_hi_green(), "\x1b[102m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_hi_yellow(), "\x1b[103m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_hi_blue(), "\x1b[104m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_hi_magenta(), "\x1b[105m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_hi_cyan(), "\x1b[106m hello \x1b[49m");
+/// assert_eq!(" hello ".bg_hi_white(), "\x1b[107m hello \x1b[49m");
+/// ```
+pub trait RenderBackground: Render {
+    fn bg_black(&self) -> String {
+        self.render(background::BLACK, cancel::BACKGROUND)
+    }
+
+    fn bg_red(&self) -> String {
+        self.render(background::RED, cancel::BACKGROUND)
+    }
+
+    fn bg_green(&self) -> String {
+        self.render(background::GREEN, cancel::BACKGROUND)
+    }
+
+    fn bg_yellow(&self) -> String {
+        self.render(background::YELLOW, cancel::BACKGROUND)
+    }
+
+    fn bg_blue(&self) -> String {
+        self.render(background::BLUE, cancel::BACKGROUND)
+    }
+
+    fn bg_magenta(&self) -> String {
+        self.render(background::MAGENTA, cancel::BACKGROUND)
+    }
+
+    fn bg_cyan(&self) -> String {
+        self.render(background::CYAN, cancel::BACKGROUND)
+    }
+
+    fn bg_white(&self) -> String {
+        self.render(background::WHITE, cancel::BACKGROUND)
+    }
+
+    fn bg_hi_black(&self) -> String {
+        self.render(background::BRIGHT_BLACK, cancel::BACKGROUND)
+    }
+
+    fn bg_hi_red(&self) -> String {
+        self.render(background::BRIGHT_RED, cancel::BACKGROUND)
+    }
+
+    fn bg_hi_green(&self) -> String {
+        self.render(background::BRIGHT_GREEN, cancel::BACKGROUND)
+    }
+
+    fn bg_hi_yellow(&self) -> String {
+        self.render(background::BRIGHT_YELLOW, cancel::BACKGROUND)
+    }
+
+    fn bg_hi_blue(&self) -> String {
+        self.render(background::BRIGHT_BLUE, cancel::BACKGROUND)
+    }
+
+    fn bg_hi_magenta(&self) -> String {
+        self.render(background::BRIGHT_MAGENTA, cancel::BACKGROUND)
+    }
+
+    fn bg_hi_cyan(&self) -> String {
+        self.render(background::BRIGHT_CYAN, cancel::BACKGROUND)
+    }
+
+    fn bg_hi_white(&self) -> String {
+        self.render(background::BRIGHT_WHITE, cancel::BACKGROUND)
+    }
+}
+
+impl<T: Render + AsRef<str>> RenderStyle for T {}
+impl<T: Render + AsRef<str>> RenderStyleAlias for T {}
+impl<T: Render + AsRef<str>> RenderForeground for T {}
+impl<T: Render + AsRef<str>> RenderBackground for T {}
@@ -0,0 +1,166 @@
+//! Spinner animations for activities without a known progress.
+//!
+//! A [Spinner] is only a list of frames,
+//! and a [Ticker] steps through them with a message.
+//! The ticker never reads the clock by itself:
+//! call [Ticker::tick] on each redraw, or [Ticker::at] with the elapsed
+//! time, so that the frames are deterministic in tests.
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::{foreground::*, spinner::*};
+//!
+//! let mut ticker = Ticker::new(Spinner::LINE).message("loading");
+//! assert_eq!(ticker.tick(), "- loading");
+//! assert_eq!(ticker.tick(), "\\ loading");
+//!
+//! let mut ticker = Ticker::new(Spinner::LINE).colors([RED, GREEN]);
+//! let frames: Vec<String> = ticker.by_ref().take(3).collect();
+//! assert_eq!(frames, vec![
+//!     "\x1b[31m-\x1b[39m",
+//!     "\x1b[32m\\\x1b[39m",
+//!     "\x1b[31m|\x1b[39m",
+//! ]);
+//! ```
+
+use std::time::Duration;
+
+use crate::{decorate::Decorate, escape::cancel};
+
+/// Frames of a spinner animation, and the suggested interval between them.
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Spinner {
+    pub frames: &'static [&'static str],
+    pub interval: Duration,
+}
+
+impl Spinner {
+    /// Braille dots moving around.
+    pub const DOTS: Spinner = Spinner {
+        frames: &["⠋", "⠙", "⠹", "⠸", "⠼", "⠴", "⠦", "⠧", "⠇", "⠏"],
+        interval: Duration::from_millis(80),
+    };
+
+    /// ASCII line rotating, works everywhere.
+    pub const LINE: Spinner = Spinner {
+        frames: &["-", "\\", "|", "/"],
+        interval: Duration::from_millis(130),
+    };
+
+    /// Quarter arcs rotating.
+    pub const ARC: Spinner = Spinner {
+        frames: &["◜", "◠", "◝", "◞", "◡", "◟"],
+        interval: Duration::from_millis(100),
+    };
+
+    /// Full braille cell with a dot missing.
+    pub const BRAILLE: Spinner = Spinner {
+        frames: &["⣾", "⣽", "⣻", "⢿", "⡿", "⣟", "⣯", "⣷"],
+        interval: Duration::from_millis(80),
+    };
+
+    /// The frame at the given index, wrapping around.
+    /// A spinner without any frame returns an empty string.
+    pub fn frame(&self, index: usize) -> &'static str {
+        match self.frames.len() {
+            0 => "",
+            len => self.frames[index % len],
+        }
+    }
+}
+
+/// Steps through the frames of a [Spinner],
+/// each frame followed by a message.
+///
+/// It's also an endless [Iterator] of the rendered frames.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Ticker {
+    pub spinner: Spinner,
+
+    /// Foreground escapes of the frames, in turn,
+    /// such as the [foreground](crate::foreground) constants.
+    /// Frames are not styled if it's empty.
+    pub colors: Vec<&'static str>,
+    pub message: String,
+
+    /// Index of the next frame.
+    pub index: usize,
+}
+
+impl Ticker {
+    pub fn new(spinner: Spinner) -> Self {
+        Ticker {
+            spinner,
+            colors: Vec::new(),
+            message: String::new(),
+            index: 0,
+        }
+    }
+
+    pub fn colors<I: IntoIterator<Item = &'static str>>(
+        mut self,
+        colors: I,
+    ) -> Self {
+        self.colors = colors.into_iter().collect();
+        self
+    }
+
+    pub fn message<T: Into<String>>(mut self, message: T) -> Self {
+        self.message = message.into();
+        self
+    }
+
+    /// Replace the message without resetting the frames.
+    pub fn set_message<T: Into<String>>(&mut self, message: T) {
+        self.message = message.into();
+    }
+
+    /// Render the frame at the given index with the message.
+    pub fn render(&self, index: usize) -> String {
+        let frame = match self.colors.len() {
+            0 => self.spinner.frame(index).to_string(),
+            len => self
+                .spinner
+                .frame(index)
+                .wrap_escape(self.colors[index % len], cancel::FOREGROUND),
+        };
+        match self.message.is_empty() {
+            true => frame,
+            false => format!("{frame} {}", self.message),
+        }
+    }
+
+    /// Render the next frame, and step forward.
+    pub fn tick(&mut self) -> String {
+        let frame = self.render(self.index);
+        self.index = self.index.wrapping_add(1);
+        frame
+    }
+
+    /// Render the frame that should be shown after the elapsed time
+    /// since the start, according to the interval of the spinner.
+    /// It doesn't step the ticker.
+    ///
+    /// ```rust
+    /// use std::time::Duration;
+    /// use terminal_font::spinner::*;
+    ///
+    /// let ticker = Ticker::new(Spinner::ARC);
+    /// assert_eq!(ticker.at(Duration::from_millis(0)), "◜");
+    /// assert_eq!(ticker.at(Duration::from_millis(250)), "◝");
+    /// assert_eq!(ticker.at(Duration::from_millis(650)), "◜");
+    /// ```
+    pub fn at(&self, elapsed: Duration) -> String {
+        let interval = self.spinner.interval.as_nanos().max(1);
+        self.render((elapsed.as_nanos() / interval) as usize)
+    }
+}
+
+impl Iterator for Ticker {
+    type Item = String;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        Some(self.tick())
+    }
+}
@@ -0,0 +1,432 @@
+//! Tables with aligned columns and styled cells.
+//!
+//! Column widths are measured by the
+//! [display width](crate::unicode::display_width),
+//! so decorated and wide contents are aligned as expected.
+//!
+//! ```rust
+//! use terminal_font::{border::Border, detect::*, table::*, text::Alignment};
+//!
+//! set_color_depth(ColorDepth::None);
+//! let table = Table::new()
+//!     .border(Border::ASCII)
+//!     .columns([Column::new(), Column::new().align(Alignment::Right)])
+//!     .header(["name", "size"])
+//!     .row(["a.txt", "12"])
+//!     .row(["long.txt", "3456"]);
+//! assert_eq!(table.render(), "\
+//! +----------+------+
+//! | name     | size |
+//! +----------+------+
+//! | a.txt    |   12 |
+//! | long.txt | 3456 |
+//! +----------+------+");
+//! ```
+//!
+//! Overflowing cells are wrapped or truncated according to the [Column]:
+//!
+//! ```rust
+//! use terminal_font::{border::Border, detect::*, table::*};
+//!
+//! set_color_depth(ColorDepth::None);
+//! let table = Table::new()
+//!     .border(Border::ROUNDED)
+//!     .columns([
+//!         Column::new().max_width(5),
+//!         Column::new().max_width(4).overflow(Overflow::Truncate),
+//!     ])
+//!     .row(["hello world", "abcdef"]);
+//! assert_eq!(table.to_string(), "\
+//! ╭───────┬──────╮
+//! │ hello │ abc… │
+//! │ world │      │
+//! ╰───────┴──────╯");
+//! ```
+
+use std::fmt::{self, Display, Formatter};
+
+use crate::{
+    border::Border,
+    compose::{Color, Style},
+    text::{align, truncate, word_wrap, Alignment},
+    unicode::display_width,
+};
+
+/// How to handle the cell contents wider than the column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Overflow {
+    /// Break into multiple lines, see [word_wrap].
+    #[default]
+    Wrap,
+
+    /// Cut each line with an ellipsis, see [truncate].
+    Truncate,
+}
+
+/// Layout of a column of the [Table].
+/// The width of the column is the width of its widest cell,
+/// limited between the min and max widths.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Column {
+    pub alignment: Alignment,
+    pub min_width: usize,
+    pub max_width: Option<usize>,
+    pub overflow: Overflow,
+}
+
+impl Column {
+    pub const fn new() -> Self {
+        Column {
+            alignment: Alignment::Left,
+            min_width: 0,
+            max_width: None,
+            overflow: Overflow::Wrap,
+        }
+    }
+
+    pub const fn align(mut self, alignment: Alignment) -> Self {
+        self.alignment = alignment;
+        self
+    }
+
+    pub const fn min_width(mut self, width: usize) -> Self {
+        self.min_width = width;
+        self
+    }
+
+    /// Limit the width of the column, which takes precedence over
+    /// the min width. Zero is treated as one.
+    pub const fn max_width(mut self, width: usize) -> Self {
+        self.max_width = Some(width);
+        self
+    }
+
+    pub const fn overflow(mut self, overflow: Overflow) -> Self {
+        self.overflow = overflow;
+        self
+    }
+}
+
+/// A cell of the [Table], whose content might be decorated
+/// or contain multiple lines.
+#[derive(Clone, Debug, Default, PartialEq, Eq, Hash)]
+pub struct Cell {
+    pub content: String,
+    pub style: Style,
+}
+
+impl Cell {
+    pub fn new<T: Into<String>>(content: T) -> Self {
+        Cell {
+            content: content.into(),
+            style: Style::new(),
+        }
+    }
+
+    /// Style of the cell, merged over the style of the row
+    /// (see [Style::merge]).
+    pub fn style(mut self, style: Style) -> Self {
+        self.style = style;
+        self
+    }
+}
+
+impl From<&str> for Cell {
+    fn from(value: &str) -> Self {
+        Cell::new(value)
+    }
+}
+
+impl From<String> for Cell {
+    fn from(value: String) -> Self {
+        Cell::new(value)
+    }
+}
+
+/// A table with optional header, rendered line by line.
+/// Rows might have different count of cells,
+/// and the missing cells are rendered as empty.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{compose::*, table::*};
+///
+/// let ok = Cell::new("ok").style(Style::new().fg(Color::Green));
+/// let table = Table::new()
+///     .header(["id", "status"])
+///     .row([Cell::new("1"), ok])
+///     .row(["2", "pending"])
+///     .stripe(Color::BrightBlack);
+/// let rendered = table.render();
+/// assert!(rendered.contains("\x1b[1mid\x1b[22m"));
+/// assert!(rendered.contains("\x1b[32mok\x1b[39m"));
+/// assert!(rendered.contains("\x1b[100m 2  \x1b[49m"));
+/// ```
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct Table {
+    pub columns: Vec<Column>,
+    pub header: Vec<Cell>,
+    pub rows: Vec<Vec<Cell>>,
+    /// No border is drawn if it's [None].
+    pub border: Option<Border>,
+    pub border_style: Style,
+
+    /// Style of the header row, bold by default.
+    pub header_style: Style,
+
+    /// Background color of every second row, for zebra striping.
+    pub stripe: Option<Color>,
+
+    /// Spaces on both sides of each cell.
+    pub padding: usize,
+}
+
+impl Default for Table {
+    fn default() -> Self {
+        Table {
+            columns: Vec::new(),
+            header: Vec::new(),
+            rows: Vec::new(),
+            border: Some(Border::LIGHT),
+            border_style: Style::new(),
+            header_style: Style::new().bold(),
+            stripe: None,
+            padding: 1,
+        }
+    }
+}
+
+impl Table {
+    /// An empty table with [Border::LIGHT] and bold header.
+    pub fn new() -> Self {
+        Table::default()
+    }
+
+    /// Layout of the columns, in order.
+    /// Columns not specified will use the default [Column].
+    pub fn columns<I: IntoIterator<Item = Column>>(
+        mut self,
+        columns: I,
+    ) -> Self {
+        self.columns = columns.into_iter().collect();
+        self
+    }
+
+    pub fn header<I, C>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cell>,
+    {
+        self.header = cells.into_iter().map(Into::into).collect();
+        self
+    }
+
+    /// Append a row, in the builder pattern.
+    pub fn row<I, C>(mut self, cells: I) -> Self
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cell>,
+    {
+        self.push_row(cells);
+        self
+    }
+
+    /// Append a row.
+    pub fn push_row<I, C>(&mut self, cells: I)
+    where
+        I: IntoIterator<Item = C>,
+        C: Into<Cell>,
+    {
+        self.rows.push(cells.into_iter().map(Into::into).collect());
+    }
+
+    /// Line style of the border. With [None],
+    /// there will be no horizontal line and no vertical line,
+    /// and the columns are separated by the padding only.
+    ///
+    /// ```rust
+    /// use terminal_font::{border::Border, table::Table};
+    ///
+    /// let table = Table::new().row(["a", "b"]).row(["c", "d"]);
+    /// assert_eq!(table.clone().border(None).render(), "a  b\nc  d");
+    /// let spaces = Border::uniform(' ', ' ', ' ');
+    /// let spaced = table.border(spaces).render();
+    /// assert_eq!(spaced.lines().nth(1), Some("  a   b  "));
+    /// ```
+    pub fn border<T: Into<Option<Border>>>(mut self, border: T) -> Self {
+        self.border = border.into();
+        self
+    }
+
+    /// Style of the border lines, usually a foreground color.
+    pub fn border_style(mut self, style: Style) -> Self {
+        self.border_style = style;
+        self
+    }
+
+    pub fn header_style(mut self, style: Style) -> Self {
+        self.header_style = style;
+        self
+    }
+
+    /// Set the background color of every second row (zebra striping),
+    /// the same escape as the [SimpleBackground](crate::SimpleBackground)
+    /// methods for the basic colors.
+    pub fn stripe<T: Into<Color>>(mut self, color: T) -> Self {
+        self.stripe = Some(color.into());
+        self
+    }
+
+    pub fn padding(mut self, padding: usize) -> Self {
+        self.padding = padding;
+        self
+    }
+
+    fn column(&self, index: usize) -> Column {
+        self.columns.get(index).copied().unwrap_or_default()
+    }
+
+    /// Count of columns, the max of the specified columns and the cells.
+    fn count(&self) -> usize {
+        self.rows
+            .iter()
+            .map(Vec::len)
+            .chain([self.columns.len(), self.header.len()])
+            .max()
+            .unwrap_or(0)
+    }
+
+    fn widths(&self) -> Vec<usize> {
+        (0..self.count())
+            .map(|index| {
+                let natural = std::iter::once(&self.header)
+                    .chain(&self.rows)
+                    .filter_map(|row| row.get(index))
+                    .flat_map(|cell| cell.content.split('\n'))
+                    .map(display_width)
+                    .max()
+                    .unwrap_or(0);
+                let column = self.column(index);
+                let width = natural.max(column.min_width);
+                match column.max_width {
+                    Some(max) => width.min(max.max(1)),
+                    None => width,
+                }
+            })
+            .collect()
+    }
+
+    /// Render the table into lines joined by `\n`,
+    /// without a trailing line break.
+    pub fn render(&self) -> String {
+        let widths = self.widths();
+        if widths.is_empty() {
+            return String::new();
+        }
+        let spans: Vec<usize> = widths
+            .iter()
+            .map(|width| width + self.padding * 2)
+            .collect();
+        let paint = |line: String| self.border_style.simple_apply(line);
+
+        let mut lines = Vec::new();
+        if let Some(border) = self.border {
+            lines.push(paint(border.top(&spans)));
+        }
+        if !self.header.is_empty() {
+            lines.extend(self.render_row(
+                &self.header,
+                &widths,
+                self.header_style,
+                None,
+            ));
+            if let Some(border) = self.border {
+                lines.push(paint(border.separator(&spans)));
+            }
+        }
+        for (index, row) in self.rows.iter().enumerate() {
+            let stripe = self.stripe.filter(|_| index % 2 == 1);
+            lines.extend(self.render_row(row, &widths, Style::new(), stripe));
+        }
+        if let Some(border) = self.border {
+            lines.push(paint(border.bottom(&spans)));
+        }
+        lines.join("\n")
+    }
+
+    fn render_row(
+        &self,
+        cells: &[Cell],
+        widths: &[usize],
+        style: Style,
+        stripe: Option<Color>,
+    ) -> Vec<String> {
+        let contents: Vec<Vec<String>> = widths
+            .iter()
+            .enumerate()
+            .map(|(index, &width)| {
+                let cell = cells.get(index).cloned().unwrap_or_default();
+                let lines = match self.column(index).overflow {
+                    Overflow::Wrap => word_wrap(&cell.content, width),
+                    Overflow::Truncate => word_wrap(&cell.content, usize::MAX)
+                        .into_iter()
+                        .map(|line| truncate(line, width))
+                        .collect(),
+                };
+                let style = style.merge(cell.style);
+                lines.into_iter().map(|line| style.apply(line)).collect()
+            })
+            .collect();
+        let height = contents.iter().map(Vec::len).max().unwrap_or(0);
+
+        let bordered = self.border.is_some();
+        let vertical = self.border.map_or(String::new(), |border| {
+            self.border_style.simple_apply(border.vertical.to_string())
+        });
+        let padding = " ".repeat(self.padding);
+        (0..height)
+            .map(|line_index| {
+                let mut line = String::new();
+                if bordered {
+                    line.push_str(&vertical);
+                }
+                for (index, &width) in widths.iter().enumerate() {
+                    let last = index + 1 == widths.len();
+                    if index > 0 && bordered {
+                        line.push_str(&vertical);
+                    }
+                    let content = contents[index]
+                        .get(line_index)
+                        .map(String::as_str)
+                        .unwrap_or("");
+                    let alignment = self.column(index).alignment;
+                    let mut cell = String::new();
+                    if bordered || index > 0 {
+                        cell.push_str(&padding);
+                    }
+                    cell.push_str(&align(content, width, alignment, ' '));
+                    if bordered || !last {
+                        cell.push_str(&padding);
+                    }
+                    match stripe {
+                        Some(color) => {
+                            line.push_str(&Style::new().bg(color).apply(cell))
+                        }
+                        None => line.push_str(&cell),
+                    }
+                }
+                if bordered {
+                    line.push_str(&vertical);
+                }
+                line
+            })
+            .collect()
+    }
+}
+
+impl Display for Table {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        f.write_str(&self.render())
+    }
+}
@@ -0,0 +1,541 @@
+//! Layout of decorated strings according to their
+//! [display width](crate::unicode::display_width),
+//! without breaking escape sequences or leaking styles.
+//!
+//! Byte based operations such as [String::truncate] don't work
+//! on decorated strings: they may cut an escape sequence in half,
+//! or drop the suffix that cancels the style,
+//! leaving the style active for the rest of the terminal session.
+//! The functions in this mod track the active [Style]
+//! (see [Style::update]) and close it wherever the text is cut.
+
+use std::fmt::{self, Display, Formatter, Write};
+
+use crate::{
+    compose::{Attributes, Style},
+    escape::{tokenize, ExtendedColor, SgrAttribute, SgrParams, Token},
+    unicode::{display_width, grapheme_width, graphemes},
+};
+
+/// Truncate the string to the maximum display width,
+/// ending with `…` if it's truncated.
+/// See [truncate_with] for more details.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{decorate::*, text::truncate};
+///
+/// assert_eq!(truncate("hello", 5), "hello");
+/// assert_eq!(truncate("hello world", 5), "hell…");
+/// let blue = "hello world".simple_bg_blue();
+/// assert_eq!(truncate(blue, 5), "\x1b[44mhell…\x1b[49m");
+/// ```
+pub fn truncate<T: AsRef<str>>(raw: T, width: usize) -> String {
+    truncate_with(raw, width, "…")
+}
+
+/// Truncate the string to the maximum display width,
+/// ending with the given ellipsis if it's truncated.
+///
+/// Escape sequences are never cut in half.
+/// The ellipsis inherits the style active at the cut point,
+/// and the style is then closed with the minimal cancel codes.
+/// Wide characters that don't fit are dropped entirely,
+/// so the result might be narrower than the width.
+/// If the ellipsis itself is wider than the width,
+/// the truncated ellipsis is returned.
+///
+/// SGR attributes that [Style] cannot track (such as overline)
+/// are closed with their own cancel codes, or a full reset if unknown,
+/// and an open hyperlink (OSC 8) is closed as well.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{decorate::*, text::truncate_with};
+///
+/// let raw = format!("{} world", "hello".simple_bold().simple_red());
+/// let truncated = truncate_with(&raw, 8, "...");
+/// assert_eq!(truncated, "\x1b[31m\x1b[1mhello\x1b[22m\x1b[39m...");
+/// let truncated = truncate_with(&raw, 4, "...");
+/// assert_eq!(truncated, "\x1b[31m\x1b[1mh...\x1b[22;39m");
+/// assert_eq!(truncate_with("你好世界", 5, ""), "你好");
+/// assert_eq!(truncate_with("hello", 2, "..."), "..");
+///
+/// let overline = "\x1b[53mabcdefgh\x1b[55m";
+/// assert_eq!(truncate_with(overline, 4, "…"), "\x1b[53mabc…\x1b[55m");
+/// let balanced = "\x1b[53mab\x1b[55mcdef";
+/// assert_eq!(truncate_with(balanced, 4, "…"), "\x1b[53mab\x1b[55mc…");
+/// let fraktur = "\x1b[20mabcdefgh";
+/// assert_eq!(truncate_with(fraktur, 4, "…"), "\x1b[20mabc…\x1b[0m");
+/// let link = "\x1b]8;;https://example.com\x1b\\abcdefgh\x1b]8;;\x1b\\";
+/// let truncated = truncate_with(link, 4, "…");
+/// let expected = "\x1b]8;;https://example.com\x1b\\abc…\x1b]8;;\x1b\\";
+/// assert_eq!(truncated, expected);
+/// ```
+pub fn truncate_with<T: AsRef<str>, E: AsRef<str>>(
+    raw: T,
+    width: usize,
+    ellipsis: E,
+) -> String {
+    let (raw, ellipsis) = (raw.as_ref(), ellipsis.as_ref());
+    if display_width(raw) <= width {
+        return raw.to_string();
+    }
+    let ellipsis_width = display_width(ellipsis);
+    if ellipsis_width > width {
+        return truncate_with(ellipsis, width, "");
+    }
+
+    let limit = width - ellipsis_width;
+    let mut result = String::with_capacity(raw.len() + ellipsis.len());
+    let mut style = Style::new();
+    let mut untracked = Untracked::default();
+    let mut taken = 0;
+    'tokens: for token in tokenize(raw) {
+        match token {
+            Token::Text(text) => {
+                for cluster in graphemes(text) {
+                    taken += grapheme_width(cluster);
+                    if taken > limit {
+                        break 'tokens;
+                    }
+                    result.push_str(cluster);
+                }
+            }
+            Token::Sgr { raw, params } => {
+                untracked.update_sgr(params);
+                style.update_sgr(params);
+                result.push_str(raw);
+            }
+            token => {
+                untracked.update(&token);
+                result.push_str(token.raw());
+            }
+        }
+    }
+    result.push_str(ellipsis);
+    result.push_str(&style.suffix());
+    result.push_str(&untracked.suffix());
+    result
+}
+
+/// SGR attributes not tracked by [Style] and the hyperlink (OSC 8)
+/// open in the text, which are closed separately wherever it's cut,
+/// and reopened if the text continues (see [word_wrap]).
+#[derive(Clone, Debug, Default)]
+struct Untracked<'a> {
+    /// Parameters of the open attributes and their cancel codes,
+    /// or `0` for those without a dedicated one.
+    attributes: Vec<(String, u16)>,
+
+    /// Opening escape of the open hyperlink.
+    link: Option<&'a str>,
+}
+
+impl<'a> Untracked<'a> {
+    fn update(&mut self, token: &Token<'a>) {
+        match token {
+            Token::Sgr { params, .. } => self.update_sgr(*params),
+            Token::Osc { raw, data } => {
+                if let Some(rest) = data.strip_prefix("8;") {
+                    self.link = rest
+                        .split_once(';')
+                        .filter(|(_, uri)| !uri.is_empty())
+                        .map(|_| *raw);
+                }
+            }
+            _ => {}
+        }
+    }
+
+    fn update_sgr(&mut self, params: SgrParams) {
+        for attribute in params.attributes() {
+            let (params, cancel) = match attribute {
+                SgrAttribute::Code(0) => {
+                    self.attributes.clear();
+                    continue;
+                }
+                attribute if is_tracked(attribute) => continue,
+                SgrAttribute::Code(code) if CANCELS.contains(&code) => {
+                    self.attributes.retain(|(_, cancel)| *cancel != code);
+                    continue;
+                }
+                SgrAttribute::Code(code) => {
+                    (code.to_string(), untracked_cancel(code))
+                }
+                // Underline color, the only untracked extended color.
+                SgrAttribute::Underline(ExtendedColor::Code(code)) => {
+                    (format!("58;5;{code}"), 59)
+                }
+                SgrAttribute::Underline(ExtendedColor::Rgb(rgb)) => {
+                    (format!("58;2;{};{};{}", rgb.r, rgb.g, rgb.b), 59)
+                }
+                _ => continue,
+            };
+            // Attributes sharing a cancel code replace each other.
+            self.attributes.retain(|(open, open_cancel)| match cancel {
+                0 => *open != params,
+                cancel => *open_cancel != cancel,
+            });
+            self.attributes.push((params, cancel));
+        }
+    }
+
+    /// Escapes reopening the open attributes and hyperlink.
+    fn prefix(&self) -> String {
+        let params: Vec<&str> = self
+            .attributes
+            .iter()
+            .map(|(params, _)| params.as_str())
+            .collect();
+        let mut prefix = match params.is_empty() {
+            true => String::new(),
+            false => format!("\x1b[{}m", params.join(";")),
+        };
+        prefix.push_str(self.link.unwrap_or_default());
+        prefix
+    }
+
+    /// Escapes closing the open attributes and hyperlink,
+    /// with a full reset if any attribute has no dedicated cancel code.
+    fn suffix(&self) -> String {
+        let mut cancels: Vec<u16> = Vec::new();
+        for (_, cancel) in &self.attributes {
+            if !cancels.contains(cancel) {
+                cancels.push(*cancel);
+            }
+        }
+        let mut suffix = match cancels.contains(&0) {
+            true => "\x1b[0m".to_string(),
+            false if cancels.is_empty() => String::new(),
+            false => {
+                let codes: Vec<String> =
+                    cancels.iter().map(u16::to_string).collect();
+                format!("\x1b[{}m", codes.join(";"))
+            }
+        };
+        if self.link.is_some() {
+            suffix.push_str("\x1b]8;;\x1b\\");
+        }
+        suffix
+    }
+}
+
+/// Dedicated cancel codes of the SGR attributes not tracked by [Style]:
+/// primary font, proportional spacing, frame and encircle, overline,
+/// underline color, ideogram, superscript and subscript.
+const CANCELS: [u16; 7] = [10, 50, 54, 55, 59, 65, 75];
+
+/// Cancel code of an SGR attribute not tracked by [Style],
+/// or `0` if there's no dedicated one.
+fn untracked_cancel(code: u16) -> u16 {
+    match code {
+        11..=19 => 10,
+        26 => 50,
+        51 | 52 => 54,
+        53 => 55,
+        60..=64 => 65,
+        73 | 74 => 75,
+        _ => 0,
+    }
+}
+
+/// Whether the SGR attribute is tracked by [Style::update].
+fn is_tracked(attribute: SgrAttribute) -> bool {
+    match attribute {
+        SgrAttribute::Code(code) => {
+            let colors =
+                matches!(code, 30..=37 | 40..=47 | 90..=97 | 100..=107);
+            colors
+                || matches!(code, 0 | 39 | 49)
+                || Attributes::TABLE.into_iter().any(|(_, enable, cancel)| {
+                    code == enable as u16 || code == cancel as u16
+                })
+        }
+        SgrAttribute::Foreground(_) | SgrAttribute::Background(_) => true,
+        SgrAttribute::Underline(_) => false,
+    }
+}
+
+/// Break the string into lines of the maximum display width,
+/// on the whitespaces between words.
+///
+/// Words wider than the width are broken at grapheme boundaries.
+/// Existing line breaks are kept, so are the leading whitespaces
+/// of each paragraph, while the whitespaces at the wrapping points
+/// are removed. The style active at the end of each line is closed,
+/// and reopened at the start of the next line, so that each line
+/// can be printed (or paged) on its own.
+/// So are the SGR attributes that [Style] cannot track
+/// and the hyperlink (OSC 8).
+/// A zero width is treated as one.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{decorate::*, text::word_wrap};
+///
+/// let lines = word_wrap("the quick brown fox", 10);
+/// assert_eq!(lines, vec!["the quick", "brown fox"]);
+/// assert_eq!(word_wrap("abcdefgh ij", 3), vec!["abc", "def", "gh", "ij"]);
+/// assert_eq!(word_wrap("a\n\n  b", 10), vec!["a", "", "  b"]);
+///
+/// let lines = word_wrap("hello world".simple_red(), 5);
+/// assert_eq!(lines, vec![
+///     "\x1b[31mhello\x1b[39m",
+///     "\x1b[31mworld\x1b[39m",
+/// ]);
+///
+/// let open = "\x1b]8;;https://example.com\x1b\\";
+/// let close = "\x1b]8;;\x1b\\";
+/// let link = format!("{open}\x1b[53mhello world\x1b[55m{close}");
+/// assert_eq!(word_wrap(link, 5), vec![
+///     format!("{open}\x1b[53mhello\x1b[55m{close}"),
+///     format!("\x1b[53m{open}world\x1b[55m{close}"),
+/// ]);
+/// ```
+pub fn word_wrap<T: AsRef<str>>(raw: T, width: usize) -> Vec<String> {
+    let mut wrapper = Wrapper {
+        width: width.max(1),
+        lines: Vec::new(),
+        line: String::new(),
+        line_width: 0,
+        wrapped: false,
+        style: Style::new(),
+        untracked: Untracked::default(),
+        word: Vec::new(),
+        word_width: 0,
+        spaces: Vec::new(),
+        spaces_width: 0,
+    };
+    for token in tokenize(raw.as_ref()) {
+        let Token::Text(text) = token else {
+            wrapper.word.push(token);
+            continue;
+        };
+        for cluster in graphemes(text) {
+            if cluster == "\n" || cluster == "\r\n" {
+                wrapper.flush_word();
+                wrapper.break_line(false);
+            } else if cluster.chars().all(char::is_whitespace) {
+                wrapper.flush_word();
+                wrapper.spaces.push(cluster);
+                wrapper.spaces_width += grapheme_width(cluster);
+            } else {
+                wrapper.word.push(Token::Text(cluster));
+                wrapper.word_width += grapheme_width(cluster);
+            }
+        }
+    }
+    wrapper.flush_word();
+    wrapper.break_line(false);
+    wrapper.lines
+}
+
+/// State of [word_wrap].
+struct Wrapper<'a> {
+    width: usize,
+    lines: Vec<String>,
+    line: String,
+    line_width: usize,
+
+    /// Whether current line is started by wrapping.
+    wrapped: bool,
+
+    /// The style active at the end of current line.
+    style: Style,
+    untracked: Untracked<'a>,
+
+    /// Pending word, text of each grapheme cluster and escapes.
+    word: Vec<Token<'a>>,
+    word_width: usize,
+
+    /// Pending whitespaces before the word.
+    spaces: Vec<&'a str>,
+    spaces_width: usize,
+}
+
+impl<'a> Wrapper<'a> {
+    fn push(&mut self, token: Token<'a>) {
+        match token {
+            Token::Text(cluster) => {
+                self.line.push_str(cluster);
+                self.line_width += grapheme_width(cluster);
+            }
+            Token::Sgr { raw, params } => {
+                self.untracked.update_sgr(params);
+                self.style.update_sgr(params);
+                self.line.push_str(raw);
+            }
+            token => {
+                self.untracked.update(&token);
+                self.line.push_str(token.raw());
+            }
+        }
+    }
+
+    fn flush_word(&mut self) {
+        if self.word.is_empty() {
+            return;
+        }
+        let pending = self.spaces_width + self.word_width;
+        if self.line_width > 0 && self.line_width + pending > self.width {
+            self.break_line(true);
+        }
+        let spaces = std::mem::take(&mut self.spaces);
+        self.spaces_width = 0;
+        // Whitespaces at the wrapping points are removed.
+        if self.line_width > 0 || !self.wrapped {
+            spaces
+                .into_iter()
+                .for_each(|space| self.push(Token::Text(space)));
+        }
+
+        let fits = self.line_width + self.word_width <= self.width;
+        for token in std::mem::take(&mut self.word) {
+            if let Token::Text(cluster) = token {
+                let width = grapheme_width(cluster);
+                if !fits
+                    && self.line_width > 0
+                    && self.line_width + width > self.width
+                {
+                    self.break_line(true);
+                }
+            }
+            self.push(token);
+        }
+        self.word_width = 0;
+    }
+
+    fn break_line(&mut self, wrapped: bool) {
+        self.line.push_str(&self.style.suffix());
+        self.line.push_str(&self.untracked.suffix());
+        let prefix = self.style.prefix() + &self.untracked.prefix();
+        self.lines.push(std::mem::replace(&mut self.line, prefix));
+        self.line_width = 0;
+        self.wrapped = wrapped;
+        self.spaces.clear();
+        self.spaces_width = 0;
+    }
+}
+
+/// Horizontal alignment of text inside a wider column.
+#[derive(Clone, Copy, Debug, Default, PartialEq, Eq, Hash)]
+pub enum Alignment {
+    #[default]
+    Left,
+    Center,
+    Right,
+}
+
+impl Alignment {
+    /// Count of the fill columns on the left and on the right,
+    /// to align the content of the given width into the column.
+    /// When centered, the extra column goes to the right.
+    pub fn padding(self, content: usize, column: usize) -> (usize, usize) {
+        let padding = column.saturating_sub(content);
+        match self {
+            Alignment::Left => (0, padding),
+            Alignment::Center => (padding / 2, padding - padding / 2),
+            Alignment::Right => (padding, 0),
+        }
+    }
+}
+
+/// Pad the string with the fill character to the given display width,
+/// according to the alignment.
+/// The fill character is assumed to take a single column,
+/// and the string is returned as is if it's already wide enough.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{decorate::*, text::*};
+///
+/// let ok = "ok".simple_green();
+/// let padded = align(&ok, 6, Alignment::Center, '.');
+/// assert_eq!(padded, "..\x1b[32mok\x1b[39m..");
+/// assert_eq!(align_left("你好", 6), "你好  ");
+/// assert_eq!(align_right("ok", 4), "  ok");
+/// assert_eq!(align_center("ok", 5), " ok  ");
+/// assert_eq!(align_center("hello", 2), "hello");
+/// ```
+pub fn align<T: AsRef<str>>(
+    raw: T,
+    width: usize,
+    alignment: Alignment,
+    fill: char,
+) -> String {
+    let raw = raw.as_ref();
+    let (left, right) = alignment.padding(display_width(raw), width);
+    let mut result = String::with_capacity(raw.len() + left + right);
+    result.extend(std::iter::repeat_n(fill, left));
+    result.push_str(raw);
+    result.extend(std::iter::repeat_n(fill, right));
+    result
+}
+
+/// Align to the left by padding spaces on the right, see [align].
+pub fn align_left<T: AsRef<str>>(raw: T, width: usize) -> String {
+    align(raw, width, Alignment::Left, ' ')
+}
+
+/// Align to the right by padding spaces on the left, see [align].
+pub fn align_right<T: AsRef<str>>(raw: T, width: usize) -> String {
+    align(raw, width, Alignment::Right, ' ')
+}
+
+/// Align to the center by padding spaces on both sides, see [align].
+pub fn align_center<T: AsRef<str>>(raw: T, width: usize) -> String {
+    align(raw, width, Alignment::Center, ' ')
+}
+
+/// Write the content into the formatter,
+/// honoring the width, fill and alignment of the formatter
+/// according to the display width of the raw string.
+/// Strings are aligned to the left by default, as the [str] does.
+pub(crate) fn write_aligned(
+    f: &mut Formatter<'_>,
+    raw: &str,
+    write: impl FnOnce(&mut Formatter<'_>) -> fmt::Result,
+) -> fmt::Result {
+    let Some(width) = f.width() else {
+        return write(f);
+    };
+    let alignment = match f.align() {
+        Some(fmt::Alignment::Center) => Alignment::Center,
+        Some(fmt::Alignment::Right) => Alignment::Right,
+        _ => Alignment::Left,
+    };
+    let (left, right) = alignment.padding(display_width(raw), width);
+    let fill = f.fill();
+    (0..left).try_for_each(|_| f.write_char(fill))?;
+    write(f)?;
+    (0..right).try_for_each(|_| f.write_char(fill))
+}
+
+/// Display wrapper of a decorated string,
+/// which honors the width, fill and alignment of the formatter
+/// according to the display width rather than the length in bytes.
+///
+/// ```rust
+/// # use terminal_font::detect::{set_color_depth, ColorDepth};
+/// # set_color_depth(ColorDepth::TrueColor);
+/// use terminal_font::{decorate::*, text::Visible};
+///
+/// let ok = "ok".simple_green();
+/// assert_eq!(format!("{:<6}|", ok), "\x1b[32mok\x1b[39m|");
+/// assert_eq!(format!("{:<6}|", Visible(&ok)), "\x1b[32mok\x1b[39m    |");
+/// assert_eq!(format!("{:*^6}", Visible(&ok)), "**\x1b[32mok\x1b[39m**");
+/// ```
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Visible<T>(pub T);
+
+impl<T: AsRef<str>> Display for Visible<T> {
+    fn fmt(&self, f: &mut Formatter<'_>) -> fmt::Result {
+        let raw = self.0.as_ref();
+        write_aligned(f, raw, |f| f.write_str(raw))
+    }
+}
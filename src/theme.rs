@@ -0,0 +1,213 @@
+//! Semantic [Theme]s mapping [Role]s to [Style]s.
+//!
+//! Rather than choosing colors at every call site,
+//! describe what the text means with a [Role],
+//! and let the [Theme] decide how it looks:
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::{compose::*, custom_color::RGBColor, theme::*};
+//!
+//! let theme = Theme::dark();
+//! let message = format!(
+//!     "{} file not found: {}",
+//!     theme.apply(Role::Error, "error:"),
+//!     theme.apply(Role::Code, "config.toml"),
+//! );
+//! assert!(message.starts_with("\x1b[1;91merror:\x1b[22;39m"));
+//!
+//! let salmon = Style::new().fg(RGBColor::from(0xff5f5f));
+//! let custom = theme.with(Role::Error, salmon);
+//! let error = custom.apply(Role::Error, "x");
+//! assert_eq!(error, "\x1b[38;2;255;95;95mx\x1b[39m");
+//! ```
+
+use std::{fmt, str::FromStr};
+
+use crate::{
+    compose::{Color, Style},
+    custom_color::RGBColor,
+};
+
+/// Semantic roles of text, to be styled by a [Theme].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub enum Role {
+    Error,
+    Warning,
+    Success,
+    Info,
+    Muted,
+    Accent,
+    Heading,
+    Code,
+    Link,
+    Emphasis,
+    Strong,
+}
+
+impl Role {
+    /// All the roles, in the order of declaration.
+    pub const ALL: [Role; 11] = [
+        Role::Error,
+        Role::Warning,
+        Role::Success,
+        Role::Info,
+        Role::Muted,
+        Role::Accent,
+        Role::Heading,
+        Role::Code,
+        Role::Link,
+        Role::Emphasis,
+        Role::Strong,
+    ];
+
+    /// The lowercase name of the role, such as `error`.
+    pub fn name(self) -> &'static str {
+        match self {
+            Role::Error => "error",
+            Role::Warning => "warning",
+            Role::Success => "success",
+            Role::Info => "info",
+            Role::Muted => "muted",
+            Role::Accent => "accent",
+            Role::Heading => "heading",
+            Role::Code => "code",
+            Role::Link => "link",
+            Role::Emphasis => "emphasis",
+            Role::Strong => "strong",
+        }
+    }
+}
+
+impl fmt::Display for Role {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str(self.name())
+    }
+}
+
+/// Error when parsing an unknown [Role] name.
+#[derive(Clone, Debug, PartialEq, Eq, Hash)]
+pub struct UnknownRoleError(pub String);
+
+impl fmt::Display for UnknownRoleError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "unknown role: {}", self.0)
+    }
+}
+
+impl std::error::Error for UnknownRoleError {}
+
+impl FromStr for Role {
+    type Err = UnknownRoleError;
+
+    /// Parse a role from its [name](Role::name), case-insensitive.
+    ///
+    /// ```rust
+    /// use terminal_font::theme::Role;
+    ///
+    /// assert_eq!("Warning".parse(), Ok(Role::Warning));
+    /// assert!("nope".parse::<Role>().is_err());
+    /// ```
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        Role::ALL
+            .into_iter()
+            .find(|role| role.name().eq_ignore_ascii_case(s))
+            .ok_or_else(|| UnknownRoleError(s.to_string()))
+    }
+}
+
+/// A set of [Style]s, one for each [Role].
+/// The default theme is [Theme::dark].
+#[derive(Clone, Copy, Debug, PartialEq, Eq, Hash)]
+pub struct Theme {
+    styles: [Style; Role::ALL.len()],
+}
+
+impl Default for Theme {
+    fn default() -> Self {
+        Theme::dark()
+    }
+}
+
+impl Theme {
+    /// A theme with all roles plain, to be customized by [Theme::with].
+    pub const fn plain() -> Self {
+        Theme {
+            styles: [Style::new(); Role::ALL.len()],
+        }
+    }
+
+    /// Built-in theme for terminals with a dark background,
+    /// using the bright basic colors.
+    pub fn dark() -> Self {
+        Theme::plain()
+            .with(Role::Error, Style::new().bold().fg(Color::BrightRed))
+            .with(Role::Warning, Style::new().fg(Color::BrightYellow))
+            .with(Role::Success, Style::new().fg(Color::BrightGreen))
+            .with(Role::Info, Style::new().fg(Color::BrightCyan))
+            .with(Role::Muted, Style::new().fg(Color::BrightBlack))
+            .with(Role::Accent, Style::new().fg(Color::BrightMagenta))
+            .with(Role::Heading, Style::new().bold().fg(Color::BrightBlue))
+            .with(Role::Code, Style::new().fg(Color::Yellow))
+            .with(Role::Link, Style::new().underline().fg(Color::BrightBlue))
+            .with(Role::Emphasis, Style::new().italic())
+            .with(Role::Strong, Style::new().bold())
+    }
+
+    /// Built-in theme for terminals with a light background,
+    /// using darker colors to keep the contrast.
+    pub fn light() -> Self {
+        let rgb = |hex: u32| Color::Rgb(RGBColor::from(hex));
+        Theme::plain()
+            .with(Role::Error, Style::new().bold().fg(Color::Red))
+            .with(Role::Warning, Style::new().fg(rgb(0x9a6700)))
+            .with(Role::Success, Style::new().fg(Color::Green))
+            .with(Role::Info, Style::new().fg(Color::Blue))
+            .with(Role::Muted, Style::new().fg(rgb(0x6e7781)))
+            .with(Role::Accent, Style::new().fg(Color::Magenta))
+            .with(Role::Heading, Style::new().bold().fg(Color::Blue))
+            .with(Role::Code, Style::new().fg(rgb(0x953800)))
+            .with(Role::Link, Style::new().underline().fg(Color::Blue))
+            .with(Role::Emphasis, Style::new().italic())
+            .with(Role::Strong, Style::new().bold())
+    }
+
+    /// The style of the given role.
+    pub fn style(&self, role: Role) -> Style {
+        self.styles[role as usize]
+    }
+
+    /// Replace the style of the given role.
+    pub fn set(&mut self, role: Role, style: Style) {
+        self.styles[role as usize] = style;
+    }
+
+    /// Replace the style of the given role, in the builder pattern.
+    pub fn with(mut self, role: Role, style: Style) -> Self {
+        self.set(role, style);
+        self
+    }
+
+    /// Apply the style of the role safely, see [Style::apply].
+    pub fn apply<T: AsRef<str>>(&self, role: Role, raw: T) -> String {
+        self.style(role).apply(raw)
+    }
+
+    /// Apply the style of the role by simply wrapping,
+    /// see [Style::simple_apply].
+    ///
+    /// ```rust
+    /// # use terminal_font::detect::{set_color_depth, ColorDepth};
+    /// # set_color_depth(ColorDepth::TrueColor);
+    /// use terminal_font::theme::*;
+    ///
+    /// let theme = Theme::light();
+    /// let text = theme.simple_apply(Role::Emphasis, "x");
+    /// assert_eq!(text, "\x1b[3mx\x1b[23m");
+    /// assert_eq!(Theme::plain().simple_apply(Role::Error, "x"), "x");
+    /// ```
+    pub fn simple_apply<T: AsRef<str>>(&self, role: Role, raw: T) -> String {
+        self.style(role).simple_apply(raw)
+    }
+}
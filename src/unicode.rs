@@ -0,0 +1,525 @@
+//! Unicode utilities without any dependency,
+//! such as splitting a string into user perceived characters,
+//! and measuring the columns they take on the screen.
+//!
+//! ```rust
+//! # use terminal_font::detect::{set_color_depth, ColorDepth};
+//! # set_color_depth(ColorDepth::TrueColor);
+//! use terminal_font::{decorate::*, unicode::display_width};
+//!
+//! let decorated = "你好, world".simple_red();
+//! assert_eq!(decorated.len(), 23);
+//! assert_eq!(display_width(&decorated), 11);
+//! ```
+//!
+//! The tables inside this mod are simplified from the Unicode standard,
+//! covering the commonly used scripts and emoji sequences
+//! rather than every corner case.
+
+use crate::escape::{tokenize, Token};
+
+/// Split a string into grapheme clusters (user perceived characters).
+///
+/// A cluster is a base character followed by its combining marks,
+/// variation selectors and emoji modifiers.
+/// Emoji joined by zero width joiners, pairs of regional indicators
+/// (flags) and `\r\n` are also kept together.
+///
+/// ```rust
+/// use terminal_font::unicode::graphemes;
+///
+/// let raw = "e\u{301}👍🏽🇨🇳\r\n";
+/// let clusters: Vec<&str> = graphemes(raw).collect();
+/// assert_eq!(clusters, vec!["e\u{301}", "👍🏽", "🇨🇳", "\r\n"]);
+///
+/// let family = "👨\u{200d}👩\u{200d}👧";
+/// assert_eq!(graphemes(family).count(), 1);
+/// ```
+pub fn graphemes(raw: &str) -> Graphemes<'_> {
+    Graphemes { rest: raw }
+}
+
+/// Iterator over the grapheme clusters of a string,
+/// created by [graphemes].
+#[derive(Clone, Debug)]
+pub struct Graphemes<'a> {
+    rest: &'a str,
+}
+
+impl<'a> Iterator for Graphemes<'a> {
+    type Item = &'a str;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let mut chars = self.rest.char_indices().peekable();
+        let (_, first) = chars.next()?;
+        let mut end = first.len_utf8();
+        let mut previous = first;
+        let mut indicators = is_regional_indicator(first) as usize;
+        while let Some(&(index, c)) = chars.peek() {
+            let joined = (previous == '\r' && c == '\n')
+                || (previous != '\r' && previous != '\n' && is_extend(c))
+                || previous == '\u{200d}'
+                || (is_regional_indicator(c) && indicators % 2 == 1);
+            if !joined {
+                break;
+            }
+            if is_regional_indicator(c) {
+                indicators += 1;
+            }
+            end = index + c.len_utf8();
+            previous = c;
+            chars.next();
+        }
+        let (cluster, rest) = self.rest.split_at(end);
+        self.rest = rest;
+        Some(cluster)
+    }
+}
+
+/// Columns taken by a single character on the screen.
+///
+/// East Asian wide and fullwidth characters and emoji take two columns.
+/// Control characters (tab included), combining marks,
+/// zero width joiners and other invisible format characters take none.
+///
+/// ```rust
+/// use terminal_font::unicode::char_width;
+///
+/// assert_eq!(char_width('a'), 1);
+/// assert_eq!(char_width('中'), 2);
+/// assert_eq!(char_width('🚀'), 2);
+/// assert_eq!(char_width('\u{301}'), 0);
+/// assert_eq!(char_width('\u{200d}'), 0);
+/// assert_eq!(char_width('\n'), 0);
+/// ```
+pub fn char_width(c: char) -> usize {
+    if c.is_control() {
+        0
+    } else if in_table(c, &WIDE) {
+        2
+    } else if is_extend(c)
+        || matches!(c, '\u{200b}' | '\u{2060}'..='\u{2064}' | '\u{feff}')
+        || matches!(c, '\u{200e}' | '\u{200f}' | '\u{202a}'..='\u{202e}')
+        || matches!(c, '\u{2066}'..='\u{2069}')
+    {
+        0
+    } else {
+        1
+    }
+}
+
+/// Columns taken by a grapheme cluster (see [graphemes]) on the screen.
+///
+/// The width is decided by the base character,
+/// except that a variation selector 16 forces the emoji presentation
+/// (two columns) and a variation selector 15 forces the text presentation
+/// (one column), and a flag (a pair of regional indicators)
+/// takes two columns.
+///
+/// ```rust
+/// use terminal_font::unicode::grapheme_width;
+///
+/// assert_eq!(grapheme_width("e\u{301}"), 1);
+/// assert_eq!(grapheme_width("👨\u{200d}👩\u{200d}👧"), 2);
+/// assert_eq!(grapheme_width("❤\u{fe0f}"), 2);
+/// assert_eq!(grapheme_width("🇨🇳"), 2);
+/// ```
+pub fn grapheme_width(cluster: &str) -> usize {
+    let Some(first) = cluster.chars().next() else {
+        return 0;
+    };
+    let width = char_width(first);
+    if width == 0 {
+        0
+    } else if is_regional_indicator(first) {
+        match cluster
+            .chars()
+            .filter(|&c| is_regional_indicator(c))
+            .count()
+        {
+            2 => 2,
+            _ => 1,
+        }
+    } else if cluster.contains('\u{fe0f}') {
+        2
+    } else if cluster.contains('\u{fe0e}') {
+        1
+    } else {
+        width
+    }
+}
+
+/// Columns taken by the string on the screen,
+/// the visible width used to align decorated strings.
+/// Escape sequences (see [tokenize]) take no column,
+/// and the width of the text is the sum of its [grapheme_width]s.
+///
+/// ```rust
+/// use terminal_font::{decorate::*, unicode::display_width};
+///
+/// assert_eq!(display_width("hello"), 5);
+/// assert_eq!(display_width("hello".simple_bold().simple_red()), 5);
+/// assert_eq!(display_width("\x1b]0;title\x07日本"), 4);
+/// assert_eq!(display_width("👍🏽 ok"), 5);
+/// ```
+pub fn display_width<T: AsRef<str>>(raw: T) -> usize {
+    tokenize(raw.as_ref())
+        .map(|token| match token {
+            Token::Text(text) => graphemes(text).map(grapheme_width).sum(),
+            _ => 0,
+        })
+        .sum()
+}
+
+fn is_regional_indicator(c: char) -> bool {
+    ('\u{1f1e6}'..='\u{1f1ff}').contains(&c)
+}
+
+/// Whether the character extends the former one in a grapheme cluster,
+/// including nonspacing and enclosing marks, joiners,
+/// variation selectors, emoji modifiers and tags.
+pub fn is_extend(c: char) -> bool {
+    in_table(c, &EXTEND)
+}
+
+fn in_table(c: char, table: &[(char, char)]) -> bool {
+    table
+        .binary_search_by(|&(start, end)| {
+            if end < c {
+                std::cmp::Ordering::Less
+            } else if start > c {
+                std::cmp::Ordering::Greater
+            } else {
+                std::cmp::Ordering::Equal
+            }
+        })
+        .is_ok()
+}
+
+/// Sorted ranges of the characters that extend a grapheme cluster.
+/// Emoji modifiers are also included in the [WIDE] table,
+/// as they are wide when standing alone.
+const EXTEND: [(char, char); 210] = [
+    ('\u{0300}', '\u{036f}'),
+    ('\u{0483}', '\u{0489}'),
+    ('\u{0591}', '\u{05bd}'),
+    ('\u{05bf}', '\u{05bf}'),
+    ('\u{05c1}', '\u{05c2}'),
+    ('\u{05c4}', '\u{05c5}'),
+    ('\u{05c7}', '\u{05c7}'),
+    ('\u{0610}', '\u{061a}'),
+    ('\u{064b}', '\u{065f}'),
+    ('\u{0670}', '\u{0670}'),
+    ('\u{06d6}', '\u{06dc}'),
+    ('\u{06df}', '\u{06e4}'),
+    ('\u{06e7}', '\u{06e8}'),
+    ('\u{06ea}', '\u{06ed}'),
+    ('\u{0711}', '\u{0711}'),
+    ('\u{0730}', '\u{074a}'),
+    ('\u{07a6}', '\u{07b0}'),
+    ('\u{07eb}', '\u{07f3}'),
+    ('\u{0816}', '\u{0819}'),
+    ('\u{081b}', '\u{0823}'),
+    ('\u{0825}', '\u{0827}'),
+    ('\u{0829}', '\u{082d}'),
+    ('\u{0859}', '\u{085b}'),
+    ('\u{08d3}', '\u{08e1}'),
+    ('\u{08e3}', '\u{0902}'),
+    ('\u{093a}', '\u{093a}'),
+    ('\u{093c}', '\u{093c}'),
+    ('\u{0941}', '\u{0948}'),
+    ('\u{094d}', '\u{094d}'),
+    ('\u{0951}', '\u{0957}'),
+    ('\u{0962}', '\u{0963}'),
+    ('\u{0981}', '\u{0981}'),
+    ('\u{09bc}', '\u{09bc}'),
+    ('\u{09c1}', '\u{09c4}'),
+    ('\u{09cd}', '\u{09cd}'),
+    ('\u{09e2}', '\u{09e3}'),
+    ('\u{0a01}', '\u{0a02}'),
+    ('\u{0a3c}', '\u{0a3c}'),
+    ('\u{0a41}', '\u{0a42}'),
+    ('\u{0a47}', '\u{0a48}'),
+    ('\u{0a4b}', '\u{0a4d}'),
+    ('\u{0a70}', '\u{0a71}'),
+    ('\u{0a81}', '\u{0a82}'),
+    ('\u{0abc}', '\u{0abc}'),
+    ('\u{0ac1}', '\u{0ac5}'),
+    ('\u{0ac7}', '\u{0ac8}'),
+    ('\u{0acd}', '\u{0acd}'),
+    ('\u{0b01}', '\u{0b01}'),
+    ('\u{0b3c}', '\u{0b3c}'),
+    ('\u{0b3f}', '\u{0b3f}'),
+    ('\u{0b41}', '\u{0b44}'),
+    ('\u{0b4d}', '\u{0b4d}'),
+    ('\u{0bc0}', '\u{0bc0}'),
+    ('\u{0bcd}', '\u{0bcd}'),
+    ('\u{0c3e}', '\u{0c40}'),
+    ('\u{0c46}', '\u{0c48}'),
+    ('\u{0c4a}', '\u{0c4d}'),
+    ('\u{0cbc}', '\u{0cbc}'),
+    ('\u{0ccc}', '\u{0ccd}'),
+    ('\u{0d41}', '\u{0d44}'),
+    ('\u{0d4d}', '\u{0d4d}'),
+    ('\u{0dca}', '\u{0dca}'),
+    ('\u{0dd2}', '\u{0dd4}'),
+    ('\u{0dd6}', '\u{0dd6}'),
+    ('\u{0e31}', '\u{0e31}'),
+    ('\u{0e34}', '\u{0e3a}'),
+    ('\u{0e47}', '\u{0e4e}'),
+    ('\u{0eb1}', '\u{0eb1}'),
+    ('\u{0eb4}', '\u{0ebc}'),
+    ('\u{0ec8}', '\u{0ecd}'),
+    ('\u{0f18}', '\u{0f19}'),
+    ('\u{0f35}', '\u{0f35}'),
+    ('\u{0f37}', '\u{0f37}'),
+    ('\u{0f39}', '\u{0f39}'),
+    ('\u{0f71}', '\u{0f7e}'),
+    ('\u{0f80}', '\u{0f84}'),
+    ('\u{0f86}', '\u{0f87}'),
+    ('\u{0f8d}', '\u{0fbc}'),
+    ('\u{0fc6}', '\u{0fc6}'),
+    ('\u{102d}', '\u{1030}'),
+    ('\u{1032}', '\u{1037}'),
+    ('\u{1039}', '\u{103a}'),
+    ('\u{103d}', '\u{103e}'),
+    ('\u{1058}', '\u{1059}'),
+    ('\u{105e}', '\u{1060}'),
+    ('\u{1071}', '\u{1074}'),
+    ('\u{1082}', '\u{1082}'),
+    ('\u{1085}', '\u{1086}'),
+    ('\u{108d}', '\u{108d}'),
+    ('\u{109d}', '\u{109d}'),
+    ('\u{1160}', '\u{11ff}'),
+    ('\u{135d}', '\u{135f}'),
+    ('\u{1712}', '\u{1714}'),
+    ('\u{1732}', '\u{1734}'),
+    ('\u{1752}', '\u{1753}'),
+    ('\u{1772}', '\u{1773}'),
+    ('\u{17b4}', '\u{17b5}'),
+    ('\u{17b7}', '\u{17bd}'),
+    ('\u{17c6}', '\u{17c6}'),
+    ('\u{17c9}', '\u{17d3}'),
+    ('\u{17dd}', '\u{17dd}'),
+    ('\u{180b}', '\u{180d}'),
+    ('\u{18a9}', '\u{18a9}'),
+    ('\u{1920}', '\u{1922}'),
+    ('\u{1927}', '\u{1928}'),
+    ('\u{1932}', '\u{1932}'),
+    ('\u{1939}', '\u{193b}'),
+    ('\u{1a17}', '\u{1a18}'),
+    ('\u{1a1b}', '\u{1a1b}'),
+    ('\u{1a56}', '\u{1a56}'),
+    ('\u{1a58}', '\u{1a5e}'),
+    ('\u{1a60}', '\u{1a60}'),
+    ('\u{1a62}', '\u{1a62}'),
+    ('\u{1a65}', '\u{1a6c}'),
+    ('\u{1a73}', '\u{1a7c}'),
+    ('\u{1a7f}', '\u{1a7f}'),
+    ('\u{1ab0}', '\u{1aff}'),
+    ('\u{1b00}', '\u{1b03}'),
+    ('\u{1b34}', '\u{1b34}'),
+    ('\u{1b36}', '\u{1b3a}'),
+    ('\u{1b3c}', '\u{1b3c}'),
+    ('\u{1b42}', '\u{1b42}'),
+    ('\u{1b6b}', '\u{1b73}'),
+    ('\u{1b80}', '\u{1b81}'),
+    ('\u{1ba2}', '\u{1ba5}'),
+    ('\u{1ba8}', '\u{1ba9}'),
+    ('\u{1bab}', '\u{1bad}'),
+    ('\u{1be6}', '\u{1be6}'),
+    ('\u{1be8}', '\u{1be9}'),
+    ('\u{1bed}', '\u{1bed}'),
+    ('\u{1bef}', '\u{1bf1}'),
+    ('\u{1c2c}', '\u{1c33}'),
+    ('\u{1c36}', '\u{1c37}'),
+    ('\u{1cd0}', '\u{1cd2}'),
+    ('\u{1cd4}', '\u{1ce0}'),
+    ('\u{1ce2}', '\u{1ce8}'),
+    ('\u{1ced}', '\u{1ced}'),
+    ('\u{1cf4}', '\u{1cf4}'),
+    ('\u{1cf8}', '\u{1cf9}'),
+    ('\u{1dc0}', '\u{1dff}'),
+    ('\u{200c}', '\u{200d}'),
+    ('\u{20d0}', '\u{20f0}'),
+    ('\u{2cef}', '\u{2cf1}'),
+    ('\u{2d7f}', '\u{2d7f}'),
+    ('\u{2de0}', '\u{2dff}'),
+    ('\u{302a}', '\u{302d}'),
+    ('\u{3099}', '\u{309a}'),
+    ('\u{a66f}', '\u{a672}'),
+    ('\u{a674}', '\u{a67d}'),
+    ('\u{a69e}', '\u{a69f}'),
+    ('\u{a6f0}', '\u{a6f1}'),
+    ('\u{a802}', '\u{a802}'),
+    ('\u{a806}', '\u{a806}'),
+    ('\u{a80b}', '\u{a80b}'),
+    ('\u{a825}', '\u{a826}'),
+    ('\u{a8c4}', '\u{a8c5}'),
+    ('\u{a8e0}', '\u{a8f1}'),
+    ('\u{a8ff}', '\u{a8ff}'),
+    ('\u{a926}', '\u{a92d}'),
+    ('\u{a947}', '\u{a951}'),
+    ('\u{a980}', '\u{a982}'),
+    ('\u{a9b3}', '\u{a9b3}'),
+    ('\u{a9b6}', '\u{a9b9}'),
+    ('\u{a9bc}', '\u{a9bd}'),
+    ('\u{a9e5}', '\u{a9e5}'),
+    ('\u{aa29}', '\u{aa2e}'),
+    ('\u{aa31}', '\u{aa32}'),
+    ('\u{aa35}', '\u{aa36}'),
+    ('\u{aa43}', '\u{aa43}'),
+    ('\u{aa4c}', '\u{aa4c}'),
+    ('\u{aa7c}', '\u{aa7c}'),
+    ('\u{aab0}', '\u{aab0}'),
+    ('\u{aab2}', '\u{aab4}'),
+    ('\u{aab7}', '\u{aab8}'),
+    ('\u{aabe}', '\u{aabf}'),
+    ('\u{aac1}', '\u{aac1}'),
+    ('\u{aaec}', '\u{aaed}'),
+    ('\u{aaf6}', '\u{aaf6}'),
+    ('\u{abe5}', '\u{abe5}'),
+    ('\u{abe8}', '\u{abe8}'),
+    ('\u{abed}', '\u{abed}'),
+    ('\u{fb1e}', '\u{fb1e}'),
+    ('\u{fe00}', '\u{fe0f}'),
+    ('\u{fe20}', '\u{fe2f}'),
+    ('\u{101fd}', '\u{101fd}'),
+    ('\u{102e0}', '\u{102e0}'),
+    ('\u{10376}', '\u{1037a}'),
+    ('\u{10a01}', '\u{10a0f}'),
+    ('\u{10a38}', '\u{10a3f}'),
+    ('\u{11001}', '\u{11001}'),
+    ('\u{11038}', '\u{11046}'),
+    ('\u{1107f}', '\u{11081}'),
+    ('\u{110b3}', '\u{110b6}'),
+    ('\u{110b9}', '\u{110ba}'),
+    ('\u{11100}', '\u{11102}'),
+    ('\u{11127}', '\u{1112b}'),
+    ('\u{1112d}', '\u{11134}'),
+    ('\u{16af0}', '\u{16af4}'),
+    ('\u{16b30}', '\u{16b36}'),
+    ('\u{1bc9d}', '\u{1bc9e}'),
+    ('\u{1d167}', '\u{1d169}'),
+    ('\u{1d17b}', '\u{1d182}'),
+    ('\u{1d185}', '\u{1d18b}'),
+    ('\u{1d1aa}', '\u{1d1ad}'),
+    ('\u{1e000}', '\u{1e02a}'),
+    ('\u{1e8d0}', '\u{1e8d6}'),
+    ('\u{1e944}', '\u{1e94a}'),
+    ('\u{1f3fb}', '\u{1f3ff}'),
+    ('\u{e0020}', '\u{e007f}'),
+    ('\u{e0100}', '\u{e01ef}'),
+];
+
+/// Sorted ranges of East Asian wide and fullwidth characters,
+/// and emoji with the default emoji presentation.
+const WIDE: [(char, char); 107] = [
+    ('\u{1100}', '\u{115f}'),
+    ('\u{231a}', '\u{231b}'),
+    ('\u{2329}', '\u{232a}'),
+    ('\u{23e9}', '\u{23ec}'),
+    ('\u{23f0}', '\u{23f0}'),
+    ('\u{23f3}', '\u{23f3}'),
+    ('\u{25fd}', '\u{25fe}'),
+    ('\u{2614}', '\u{2615}'),
+    ('\u{2648}', '\u{2653}'),
+    ('\u{267f}', '\u{267f}'),
+    ('\u{2693}', '\u{2693}'),
+    ('\u{26a1}', '\u{26a1}'),
+    ('\u{26aa}', '\u{26ab}'),
+    ('\u{26bd}', '\u{26be}'),
+    ('\u{26c4}', '\u{26c5}'),
+    ('\u{26ce}', '\u{26ce}'),
+    ('\u{26d4}', '\u{26d4}'),
+    ('\u{26ea}', '\u{26ea}'),
+    ('\u{26f2}', '\u{26f3}'),
+    ('\u{26f5}', '\u{26f5}'),
+    ('\u{26fa}', '\u{26fa}'),
+    ('\u{26fd}', '\u{26fd}'),
+    ('\u{2705}', '\u{2705}'),
+    ('\u{270a}', '\u{270b}'),
+    ('\u{2728}', '\u{2728}'),
+    ('\u{274c}', '\u{274c}'),
+    ('\u{274e}', '\u{274e}'),
+    ('\u{2753}', '\u{2755}'),
+    ('\u{2757}', '\u{2757}'),
+    ('\u{2795}', '\u{2797}'),
+    ('\u{27b0}', '\u{27b0}'),
+    ('\u{27bf}', '\u{27bf}'),
+    ('\u{2b1b}', '\u{2b1c}'),
+    ('\u{2b50}', '\u{2b50}'),
+    ('\u{2b55}', '\u{2b55}'),
+    ('\u{2e80}', '\u{2e99}'),
+    ('\u{2e9b}', '\u{2ef3}'),
+    ('\u{2f00}', '\u{2fd5}'),
+    ('\u{2ff0}', '\u{2ffb}'),
+    ('\u{3000}', '\u{3029}'),
+    ('\u{302e}', '\u{303e}'),
+    ('\u{3041}', '\u{3096}'),
+    ('\u{309b}', '\u{30ff}'),
+    ('\u{3105}', '\u{312f}'),
+    ('\u{3131}', '\u{318e}'),
+    ('\u{3190}', '\u{31e3}'),
+    ('\u{31f0}', '\u{321e}'),
+    ('\u{3220}', '\u{3247}'),
+    ('\u{3250}', '\u{4dbf}'),
+    ('\u{4e00}', '\u{a48c}'),
+    ('\u{a490}', '\u{a4c6}'),
+    ('\u{a960}', '\u{a97c}'),
+    ('\u{ac00}', '\u{d7a3}'),
+    ('\u{f900}', '\u{faff}'),
+    ('\u{fe10}', '\u{fe19}'),
+    ('\u{fe30}', '\u{fe52}'),
+    ('\u{fe54}', '\u{fe66}'),
+    ('\u{fe68}', '\u{fe6b}'),
+    ('\u{ff01}', '\u{ff60}'),
+    ('\u{ffe0}', '\u{ffe6}'),
+    ('\u{16fe0}', '\u{16fe4}'),
+    ('\u{17000}', '\u{187f7}'),
+    ('\u{18800}', '\u{18cd5}'),
+    ('\u{1b000}', '\u{1b122}'),
+    ('\u{1b150}', '\u{1b152}'),
+    ('\u{1b164}', '\u{1b167}'),
+    ('\u{1b170}', '\u{1b2fb}'),
+    ('\u{1f004}', '\u{1f004}'),
+    ('\u{1f0cf}', '\u{1f0cf}'),
+    ('\u{1f18e}', '\u{1f18e}'),
+    ('\u{1f191}', '\u{1f19a}'),
+    ('\u{1f200}', '\u{1f202}'),
+    ('\u{1f210}', '\u{1f23b}'),
+    ('\u{1f240}', '\u{1f248}'),
+    ('\u{1f250}', '\u{1f251}'),
+    ('\u{1f260}', '\u{1f265}'),
+    ('\u{1f300}', '\u{1f320}'),
+    ('\u{1f32d}', '\u{1f335}'),
+    ('\u{1f337}', '\u{1f37c}'),
+    ('\u{1f37e}', '\u{1f393}'),
+    ('\u{1f3a0}', '\u{1f3ca}'),
+    ('\u{1f3cf}', '\u{1f3d3}'),
+    ('\u{1f3e0}', '\u{1f3f0}'),
+    ('\u{1f3f4}', '\u{1f3f4}'),
+    ('\u{1f3f8}', '\u{1f43e}'),
+    ('\u{1f440}', '\u{1f440}'),
+    ('\u{1f442}', '\u{1f4fc}'),
+    ('\u{1f4ff}', '\u{1f53d}'),
+    ('\u{1f54b}', '\u{1f54e}'),
+    ('\u{1f550}', '\u{1f567}'),
+    ('\u{1f57a}', '\u{1f57a}'),
+    ('\u{1f595}', '\u{1f596}'),
+    ('\u{1f5a4}', '\u{1f5a4}'),
+    ('\u{1f5fb}', '\u{1f64f}'),
+    ('\u{1f680}', '\u{1f6c5}'),
+    ('\u{1f6cc}', '\u{1f6cc}'),
+    ('\u{1f6d0}', '\u{1f6d2}'),
+    ('\u{1f6d5}', '\u{1f6d7}'),
+    ('\u{1f6eb}', '\u{1f6ec}'),
+    ('\u{1f6f4}', '\u{1f6fc}'),
+    ('\u{1f7e0}', '\u{1f7eb}'),
+    ('\u{1f90c}', '\u{1f93a}'),
+    ('\u{1f93c}', '\u{1f945}'),
+    ('\u{1f947}', '\u{1f9ff}'),
+    ('\u{1fa70}', '\u{1faff}'),
+    ('\u{20000}', '\u{2fffd}'),
+    ('\u{30000}', '\u{3fffd}'),
+];